@@ -1,159 +1,555 @@
 use crossterm::event::{KeyCode, KeyEvent};
 use ratatui::{
     layout::{Alignment, Margin, Rect},
-    style::{Color, Style},
+    style::Color,
     text::Line,
-    widgets::{Clear, Paragraph},
+    widgets::Paragraph,
     Frame,
 };
 use crate::{
-    card::{Hand, Shoe, Worth},
+    card::{Card, Hand, Shoe, Suit, Total, Value, Worth},
+    chips::{net, Chips, Error, Round, Wallet, HOUSE, STEP},
+    fair::Fair,
     play::Play,
-    show::{render_hand, render_sum},
+    show::{render_focus, render_help, render_mask, render_pop, render_shoe, render_status, render_sum},
 };
 
 #[derive(PartialEq)]
 pub enum Phase {
+    Variant,
+    Seed,
     Bet,
+    Switch,
     Player,
     Dealer,
     Result,
     End,
 }
 
+#[derive(Clone, Copy)]
+pub struct Rules {
+    pub name: &'static str,
+    pub decks: u8,
+    pub tens: bool,
+    pub hidden: usize,
+    pub natural: (u64, u64),
+    pub ties: bool,
+    pub soft: bool,
+    pub stick: usize,
+    pub bonus: bool,
+    pub trick: bool,
+    pub free: bool,
+    pub push: bool,
+    pub switch: bool,
+    pub words: [&'static str; 3],
+}
+
+pub const CLASSIC: Rules = Rules {
+    name: "Classic",
+    decks: 4,
+    tens: true,
+    hidden: 1,
+    natural: (3, 2),
+    ties: false,
+    soft: false,
+    stick: 0,
+    bonus: false,
+    trick: false,
+    free: false,
+    push: false,
+    switch: false,
+    words: ["Hit", "Stand", "Double"],
+};
+
+pub const SPANISH: Rules = Rules {
+    name: "Spanish 21",
+    decks: 6,
+    tens: false,
+    bonus: true,
+    ..CLASSIC
+};
+
+pub const PONTOON: Rules = Rules {
+    name: "Pontoon",
+    hidden: 2,
+    natural: (2, 1),
+    ties: true,
+    soft: true,
+    stick: 15,
+    trick: true,
+    words: ["Twist", "Stick", "Buy"],
+    ..CLASSIC
+};
+
+pub const EXPOSURE: Rules = Rules {
+    name: "Double Exposure",
+    decks: 6,
+    hidden: 0,
+    natural: (1, 1),
+    ties: true,
+    ..CLASSIC
+};
+
+pub const FREE: Rules = Rules {
+    name: "Free Bet",
+    decks: 6,
+    soft: true,
+    free: true,
+    push: true,
+    ..CLASSIC
+};
+
+pub const SWITCH: Rules = Rules {
+    name: "Blackjack Switch",
+    decks: 6,
+    natural: (1, 1),
+    soft: true,
+    push: true,
+    switch: true,
+    ..CLASSIC
+};
+
+pub const VARIANTS: [Rules; 6] = [CLASSIC, SPANISH, PONTOON, EXPOSURE, FREE, SWITCH];
+
+impl Rules {
+    pub fn shoe(&self) -> Shoe {
+        let mut shoe = Shoe::new(self.decks);
+        if !self.tens {
+            shoe.cards.retain(|card| card.value != Value::Ten);
+        }
+        shoe
+    }
+
+    pub fn bonus(&self, hand: &Hand) -> Option<((u64, u64), &'static str)> {
+        if !self.bonus || hand.total().sum != 21 {
+            return None;
+        }
+
+        let mut values: Vec<u8> = hand.cards.iter().map(|card| card.value.score()).collect();
+        values.sort();
+        if values == [6, 7, 8] || values == [7, 7, 7] {
+            let suit = hand.cards[0].suit;
+            let suited = hand.cards.iter().all(|card| card.suit == suit);
+            let name = if values == [7, 7, 7] { "7-7-7" } else { "6-7-8" };
+            return Some(match (suited, suit) {
+                (true, Suit::Spades) => ((3, 1), name),
+                (true, _) => ((2, 1), name),
+                _ => ((3, 2), name),
+            });
+        }
+
+        match hand.cards.len() {
+            0..=4 => None,
+            5 => Some(((3, 2), "Five Card 21")),
+            6 => Some(((2, 1), "Six Card 21")),
+            _ => Some(((3, 1), "Seven Card 21")),
+        }
+    }
+
+    pub fn free_double(&self, hand: &Hand) -> bool {
+        let total = hand.total();
+        self.free && hand.cards.len() == 2 && !total.soft && (9..=11).contains(&total.sum)
+    }
+
+    pub fn free_split(&self, hand: &Hand) -> bool {
+        self.free && hand.cards[0].worth() != 10
+    }
+
+    pub fn hits(&self, total: Total) -> bool {
+        total.sum < 17 || (self.soft && total.sum == 17 && total.soft)
+    }
+
+    pub fn matched(&self, spots: &[Spot]) -> Option<(u64, &'static str)> {
+        if !self.switch {
+            return None;
+        }
+
+        let mut counts = [0u8; 16];
+        for spot in spots {
+            for card in &spot.hand.cards {
+                counts[card.value.score() as usize] += 1;
+            }
+        }
+        let mut groups: Vec<u8> = counts.iter().copied().filter(|&count| count >= 2).collect();
+        groups.sort();
+
+        Some(match groups.as_slice() {
+            [4] => (40, "Four of a Kind"),
+            [2, 2] => (8, "Two Pair"),
+            [3] => (5, "Three of a Kind"),
+            [2] => (1, "Pair"),
+            _ => (0, "No Match"),
+        })
+    }
+}
+
+#[derive(Clone)]
+pub struct Spot {
+    pub hand: Hand,
+    pub bet: Chips,
+    pub free: Chips,
+    pub doubled: bool,
+    pub split: bool,
+    pub done: bool,
+}
+
+impl Spot {
+    pub fn new(bet: Chips) -> Self {
+        Self {
+            hand: Hand::new(),
+            bet,
+            free: Chips::ZERO,
+            doubled: false,
+            split: false,
+            done: false,
+        }
+    }
+
+    pub fn natural(&self) -> bool {
+        !self.split && self.hand.total().natural
+    }
+}
+
 pub struct Blackjack {
     pub phase: Phase,
+    pub rules: Rules,
+    pub choice: usize,
     pub shoe: Shoe,
-    pub player: Hand,
+    pub fair: Fair,
+    pub client: String,
+    pub spots: Vec<Spot>,
+    pub active: usize,
     pub dealer: Hand,
-    pub money: i32,
-    pub bet: i32,
+    pub money: Wallet,
+    pub bank: Wallet,
+    pub bet: Chips,
+    pub side: Chips,
+    pub matched: Option<(u64, &'static str)>,
+    pub overlay: bool,
     pub message: String,
 }
 
 impl Blackjack {
     pub fn new() -> Self {
-        let mut shoe = Shoe::new(4);
-        shoe.shuffle();
         Self {
-            phase: Phase::Bet,
-            shoe,
-            player: Hand::new(),
+            phase: Phase::Variant,
+            rules: CLASSIC,
+            choice: 0,
+            shoe: Shoe::new(0),
+            fair: Fair::new(Vec::new()),
+            client: String::new(),
+            spots: Vec::new(),
+            active: 0,
             dealer: Hand::new(),
-            money: 1000,
-            bet: 10,
+            money: Wallet::new(Chips::ZERO),
+            bank: Wallet::new(HOUSE),
+            bet: STEP,
+            side: Chips::ZERO,
+            matched: None,
+            overlay: false,
             message: String::new(),
         }
     }
 
-    fn deal(&mut self) {
-        self.player.cards.clear();
-        self.dealer.cards.clear();
-        self.message.clear();
+    fn stake(&self) -> Result<Chips, Error> {
+        if self.rules.switch { self.bet.times(2)?.plus(self.side) } else { Ok(self.bet) }
+    }
 
-        if self.shoe.remaining() < 20 {
-            self.shoe = Shoe::new(4);
-            self.shoe.shuffle();
+    fn reveal(&mut self) {
+        let dealt = self.fair.primed.len().saturating_sub(self.shoe.primed.len());
+        self.fair.primed.truncate(dealt);
+        if let Some(path) = self.fair.save() {
+            self.message = format!("Shoe revealed in {}", path);
         }
+    }
+
+    fn reshoe(&mut self) {
+        self.reveal();
+        self.fair = Fair::new(self.rules.shoe().cards);
+        self.shoe.restock(Shoe::new(0));
+        self.client.clear();
+        self.phase = Phase::Seed;
+    }
+
+    fn deal(&mut self) -> Result<(), Error> {
+        let stake = self.stake()?;
+        self.money.transfer(&mut self.bank, stake)?;
+        self.dealer.cards.clear();
+        self.message.clear();
 
+        let count = if self.rules.switch { 2 } else { 1 };
+        self.spots = vec![Spot::new(self.bet); count];
         for _ in 0..2 {
-            self.player.add(self.shoe.draw().unwrap());
+            for spot in &mut self.spots {
+                spot.hand.add(self.shoe.draw().unwrap());
+            }
             self.dealer.add(self.shoe.draw().unwrap());
         }
+        self.active = 0;
+        self.matched = self.rules.matched(&self.spots).filter(|_| !self.side.is_zero());
+
+        if self.rules.switch {
+            self.phase = Phase::Switch;
+        } else {
+            self.start();
+        }
+        Ok(())
+    }
+
+    fn start(&mut self) {
+        for spot in &mut self.spots {
+            spot.done = spot.natural();
+        }
+        self.advance();
+    }
+
+    fn advance(&mut self) {
+        while self.active < self.spots.len() && self.spots[self.active].done {
+            self.active += 1;
+        }
+        if self.active < self.spots.len() {
+            self.phase = Phase::Player;
+            return;
+        }
+
+        if self.spots.iter().any(|spot| !spot.hand.total().bust) {
+            self.play_dealer();
+        }
+        if let Err(error) = self.resolve() {
+            self.message = error.to_string();
+        }
+        self.phase = Phase::Result;
     }
 
     fn play_dealer(&mut self) {
-        while self.dealer.worth() < 17 {
+        while self.rules.hits(self.dealer.total()) {
             self.dealer.add(self.shoe.draw().unwrap());
         }
-        self.resolve();
-    }
-
-    fn resolve(&mut self) {
-        let player = self.player.worth();
-        let dealer = self.dealer.worth();
-
-        let player_win = player == 21 && self.player.cards.len() == 2;
-        let dealer_win = dealer == 21 && self.dealer.cards.len() == 2;
-
-        if player > 21 {
-            self.message = "Bust".into();
-            self.money -= self.bet;
-        } else if dealer > 21 {
-            self.message = "Win".into();
-            self.money += self.bet;
-        } else if player_win && !dealer_win {
-            self.message = "Blackjack".into();
-            self.money += (self.bet as f32 * 1.5) as i32;
-        } else if dealer_win && !player_win {
-            self.message = "Lose".into();
-            self.money -= self.bet;
-        } else if player > dealer {
-            self.message = "Win".into();
-            self.money += self.bet;
-        } else if dealer > player {
-            self.message = "Lose".into();
-            self.money -= self.bet;
-        } else {
-            self.message = "Push".into();
+    }
+
+    fn resolve(&mut self) -> Result<(), Error> {
+        let mut results = Vec::new();
+        for spot in self.spots.clone() {
+            let (paid, label) = self.settle(&spot)?;
+            self.bank.transfer(&mut self.money, paid)?;
+            results.push(label);
+        }
+        if let Some((pays, name)) = self.matched {
+            let paid = if pays == 0 { Chips::ZERO } else { self.side.times(pays + 1)? };
+            self.bank.transfer(&mut self.money, paid)?;
+            results.push(format!("Super Match {} {}", name, net(paid, self.side)));
         }
+        self.message = results.join(", ");
 
-        if self.money <= 0 {
+        if self.money.broke() {
             self.message.push_str(" Bankrupt");
         }
+        Ok(())
     }
 
-    fn handle_bet(&mut self, key: KeyEvent) {
+    fn settle(&self, spot: &Spot) -> Result<(Chips, String), Error> {
+        let player = spot.hand.total();
+        let dealer = self.dealer.total();
+        let stake = spot.bet.plus(spot.free)?;
+        let natural = spot.natural();
+        let dealer_natural = dealer.natural;
+        let (num, den) = self.rules.natural;
+        let won = |amount: Chips| spot.bet.plus(amount);
+
+        Ok(if player.bust {
+            (Chips::ZERO, "Bust".into())
+        } else if natural && dealer_natural {
+            (spot.bet, "Push".into())
+        } else if natural {
+            (won(spot.bet.ratio(num, den, Round::Down)?)?, "Blackjack".into())
+        } else if dealer_natural {
+            (Chips::ZERO, "Lose".into())
+        } else if let Some(((num, den), name)) = self.rules.bonus(&spot.hand).filter(|_| !spot.doubled) {
+            (won(stake.ratio(num, den, Round::Down)?)?, name.into())
+        } else if self.rules.bonus && player.sum == 21 {
+            (won(stake)?, "Win".into())
+        } else if self.rules.trick && spot.hand.cards.len() >= 5 {
+            (won(stake.times(2)?)?, "Five Card Trick".into())
+        } else if self.rules.push && dealer.sum == 22 {
+            (spot.bet, "Push 22".into())
+        } else if dealer.bust || player.sum > dealer.sum {
+            (won(stake)?, "Win".into())
+        } else if player.sum < dealer.sum || self.rules.ties {
+            (Chips::ZERO, "Lose".into())
+        } else {
+            (spot.bet, "Push".into())
+        })
+    }
+
+    fn hit(&mut self) {
+        let spot = &mut self.spots[self.active];
+        spot.hand.add(self.shoe.draw().unwrap());
+        if spot.hand.total().sum >= 21 || (self.rules.trick && spot.hand.cards.len() >= 5) || spot.doubled && !self.rules.trick {
+            spot.done = true;
+        }
+        self.advance();
+    }
+
+    fn double(&mut self) -> Result<(), Error> {
+        let spot = &self.spots[self.active];
+        let limit = if self.rules.trick { 4 } else { 2 };
+        if spot.hand.cards.len() > limit || spot.doubled {
+            return Ok(());
+        }
+
+        let action = spot.bet.plus(spot.free)?;
+        if self.rules.free_double(&spot.hand) {
+            self.spots[self.active].free = spot.free.plus(action)?;
+        } else {
+            let doubled = spot.bet.plus(action)?;
+            self.money.transfer(&mut self.bank, action)?;
+            self.spots[self.active].bet = doubled;
+        }
+        self.spots[self.active].doubled = true;
+        self.hit();
+        Ok(())
+    }
+
+    fn split(&mut self) {
+        let spot = &self.spots[self.active];
+        let hand = &spot.hand;
+        if !hand.total().pair || self.spots.len() >= 4 {
+            return;
+        }
+
+        let mut other = Spot::new(spot.bet);
+        if self.rules.free_split(hand) {
+            other.bet = Chips::ZERO;
+            other.free = spot.bet;
+        } else if self.money.transfer(&mut self.bank, spot.bet).is_err() {
+            self.message = "Not enough money".into();
+            return;
+        }
+
+        let aces = hand.cards[0].value == Value::Ace;
+        let card = self.spots[self.active].hand.cards.pop().unwrap();
+        other.hand.add(card);
+        self.spots.insert(self.active + 1, other);
+
+        for index in [self.active, self.active + 1] {
+            let spot = &mut self.spots[index];
+            spot.split = true;
+            spot.hand.add(self.shoe.draw().unwrap());
+            spot.done = aces || spot.hand.total().sum == 21;
+        }
+        self.advance();
+    }
+
+    fn handle_variant(&mut self, key: KeyEvent) {
         match key.code {
             KeyCode::Char('q') => self.phase = Phase::End,
             KeyCode::Up | KeyCode::Char('w') => {
-                self.bet = (self.bet + 10).min(self.money);
+                self.choice = (self.choice + VARIANTS.len() - 1) % VARIANTS.len();
             }
             KeyCode::Down | KeyCode::Char('s') => {
-                self.bet = (self.bet - 10).max(10);
+                self.choice = (self.choice + 1) % VARIANTS.len();
             }
             KeyCode::Enter => {
-                if self.bet > self.money {
+                let rules = VARIANTS[self.choice];
+                if let Err(error) = rules.shoe().holds(&self.shoe.primed) {
+                    self.message = format!("{}: {}", rules.name, error);
                     return;
                 }
-                self.deal();
-                self.phase = Phase::Player;
+                self.rules = rules;
+                self.message.clear();
+                self.reshoe();
             }
             _ => {}
         }
     }
 
-    fn handle_player(&mut self, key: KeyEvent) {
+    fn handle_seed(&mut self, key: KeyEvent) {
         match key.code {
-            KeyCode::Char('q') => {
-                self.phase = Phase::End;
+            KeyCode::Esc => self.phase = Phase::End,
+            KeyCode::Char(code) if !code.is_whitespace() && self.client.len() < 32 => self.client.push(code),
+            KeyCode::Backspace => {
+                self.client.pop();
+            }
+            KeyCode::Enter => {
+                self.shoe.restock(self.fair.deal(&self.client));
+                self.fair.primed = self.shoe.primed.iter().rev().copied().collect();
+                self.message.clear();
+                self.phase = Phase::Bet;
+            }
+            _ => {}
+        }
+    }
+
+    fn handle_bet(&mut self, key: KeyEvent) {
+        match key.code {
+            KeyCode::Char('q') => self.phase = Phase::End,
+            KeyCode::Up | KeyCode::Char('w') => {
+                self.bet = self.bet.raise(STEP, self.money.balance());
             }
-            KeyCode::Char('h') => {
-                self.player.add(self.shoe.draw().unwrap());
-                if self.player.worth() > 21 {
-                    self.message = "Bust".into();
-                    self.resolve();
-                    self.phase = Phase::Result;
+            KeyCode::Down | KeyCode::Char('s') => {
+                self.bet = self.bet.lower(STEP, STEP);
+            }
+            KeyCode::Right | KeyCode::Char('d') if self.rules.switch => {
+                self.side = self.side.raise(STEP, self.money.balance());
+            }
+            KeyCode::Left | KeyCode::Char('a') if self.rules.switch => {
+                self.side = self.side.lower(STEP, Chips::ZERO);
+            }
+            KeyCode::Enter => {
+                match self.stake() {
+                    Ok(stake) if !self.money.covers(stake) => {
+                        self.message = format!("Need {}", stake);
+                        return;
+                    }
+                    Err(error) => {
+                        self.message = error.to_string();
+                        return;
+                    }
+                    _ => {}
+                }
+                if self.shoe.remaining() < 20 {
+                    self.reshoe();
+                    return;
                 }
+                if let Err(error) = self.deal() {
+                    self.message = error.to_string();
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn handle_switch(&mut self, key: KeyEvent) {
+        self.message.clear();
+        match key.code {
+            KeyCode::Char('x') => {
+                let first = self.spots[0].hand.cards[1];
+                self.spots[0].hand.cards[1] = self.spots[1].hand.cards[1];
+                self.spots[1].hand.cards[1] = first;
             }
+            KeyCode::Enter => self.start(),
+            _ => {}
+        }
+    }
+
+    fn handle_player(&mut self, key: KeyEvent) {
+        self.message.clear();
+        match key.code {
+            KeyCode::Char('h') | KeyCode::Char('t') => self.hit(),
             KeyCode::Char('s') => {
-                self.play_dealer();
-                self.phase = Phase::Result;
+                if self.spots[self.active].hand.total().sum < self.rules.stick {
+                    self.message = format!("Must {} below {}", self.rules.words[0].to_lowercase(), self.rules.stick);
+                    return;
+                }
+                self.spots[self.active].done = true;
+                self.advance();
             }
-            KeyCode::Char('d') => {
-                if self.player.cards.len() == 2 && self.money >= self.bet * 2 {
-                    self.bet *= 2;
-                    self.player.add(self.shoe.draw().unwrap());
-                    if self.player.worth() > 21 {
-                        self.message = "Bust".into();
-                        self.resolve();
-                        self.phase = Phase::Result;
-                    } else {
-                        self.play_dealer();
-                        self.phase = Phase::Result;
-                    }
+            KeyCode::Char('d') | KeyCode::Char('b') => {
+                if let Err(error) = self.double() {
+                    self.message = error.to_string();
                 }
             }
+            KeyCode::Char('p') => self.split(),
             _ => {}
         }
     }
@@ -162,10 +558,11 @@ impl Blackjack {
         match key.code {
             KeyCode::Char('q') => self.phase = Phase::End,
             KeyCode::Enter => {
-                if self.money <= 0 {
+                if self.money.broke() {
                     self.phase = Phase::End;
                 } else {
-                    self.bet = 10.min(self.money);
+                    self.bet = STEP.min(self.money.balance());
+                    self.side = self.side.min(self.money.balance());
                     self.message.clear();
                     self.phase = Phase::Bet;
                 }
@@ -175,42 +572,49 @@ impl Blackjack {
     }
 
     fn status(&self, frame: &mut Frame, area: Rect) {
-        let money = Line::raw(format!("Money: ${}", self.money));
-        let bet = Line::raw(format!("Bet: ${}", self.bet));
-        let width = area.width.saturating_sub(2);
-        frame.render_widget(Clear, area);
-        frame.render_widget(money, Rect::new(area.x + 2, area.y, width, 1));
-        frame.render_widget(bet, Rect::new(area.x + 2, area.y + 1, width, 1));
-    }
-
-    fn pop(&self, frame: &mut Frame, area: Rect) {
-        if self.message.is_empty() {
-            return;
-        }
-        let width = self.message.len() as u16 + 6;
-        let x = area.x + area.width.saturating_sub(width) / 2;
-        let y = area.y + area.height / 2;
-        let spot = Rect::new(x, y, width, 1);
-
-        let text = Line::raw(&self.message).style(Style::default().fg(Color::Black).bg(Color::White));
-        let item = Paragraph::new(text).alignment(Alignment::Center);
-
-        frame.render_widget(Clear, spot);
-        frame.render_widget(item, spot);
+        let lines = [
+            Line::raw(format!("Money: {}", self.money.balance())),
+            Line::raw(if self.rules.switch { format!("Bet: {} x 2  Super Match: {}", self.bet, self.side) } else { format!("Bet: {}", self.bet) }),
+            Line::raw(match self.fair.order.is_empty() {
+                true => format!("Rules: {}", self.rules.name),
+                false => format!("Rules: {}  Commitment: {}…", self.rules.name, &self.fair.commitment[..16]),
+            }),
+        ];
+        render_status(frame, &lines, area);
     }
 
     fn help(&self, frame: &mut Frame, area: Rect) {
+        let [hit, stand, double] = self.rules.words;
         let lines = match self.phase {
+            Phase::Variant => vec![
+                Line::raw("[↑↓] Variant"),
+                Line::raw("[Enter] Select"),
+                Line::raw("[Q] Quit"),
+            ],
+            Phase::Seed => vec![
+                Line::raw("[Type] Client seed"),
+                Line::raw("[Enter] Shuffle"),
+                Line::raw("[Esc] Quit"),
+            ],
+            Phase::Bet if self.rules.switch => vec![
+                Line::raw("[↑↓] Bet"),
+                Line::raw("[←→] Super Match"),
+                Line::raw("[Enter] Deal  [O] Shoe"),
+                Line::raw("[Q] Quit"),
+            ],
             Phase::Bet => vec![
                 Line::raw("[↑↓] Bet"),
-                Line::raw("[Enter] Deal"),
+                Line::raw("[Enter] Deal  [O] Shoe"),
                 Line::raw("[Q] Quit"),
             ],
+            Phase::Switch => vec![
+                Line::raw("[X] Switch second cards"),
+                Line::raw("[Enter] Play"),
+            ],
             Phase::Player => vec![
-                Line::raw("[H] Hit"),
-                Line::raw("[D] Double"),
-                Line::raw("[S] Stand"),
-                Line::raw("[Q] Quit"),
+                Line::raw(format!("[{}] {}  [{}] {}", &hit[..1], hit, &stand[..1], stand)),
+                Line::raw(format!("[{}] {}", &double[..1], double)),
+                Line::raw("[P] Split"),
             ],
             Phase::Result => vec![
                 Line::raw("[Enter] Next"),
@@ -219,13 +623,34 @@ impl Blackjack {
             _ => vec![Line::raw("")],
         };
 
-        let y = area.y + area.height.saturating_sub(lines.len() as u16);
-        let width = area.width.saturating_sub(4);
-        frame.render_widget(Clear, area);
-        for (i, line) in lines.iter().enumerate() {
-            let item = Rect::new(area.x + 2, y + i as u16, width, 1);
-            frame.render_widget(line.clone(), item);
-        }
+        render_help(frame, &lines, area);
+    }
+
+    fn variants(&self, frame: &mut Frame, area: Rect) {
+        let lines: Vec<Line> = VARIANTS
+            .iter()
+            .enumerate()
+            .map(|(i, rules)| Line::raw(format!("{} {}", if i == self.choice { ">" } else { " " }, rules.name)))
+            .collect();
+
+        let height = lines.len() as u16;
+        let item = Paragraph::new(lines).alignment(Alignment::Center);
+        let spot = Rect::new(area.x, area.y + area.height.saturating_sub(height) / 2, area.width, height);
+        frame.render_widget(item, spot);
+    }
+
+    fn seed(&self, frame: &mut Frame, area: Rect) {
+        let lines = vec![
+            Line::raw(format!("New {}-card shoe", self.fair.order.len())),
+            Line::raw(format!("Commitment: {}", self.fair.commitment)),
+            Line::raw(if self.shoe.primed.is_empty() { "" } else { "Scenario cards are dealt ahead of the committed order" }),
+            Line::raw(format!("Client seed: {}_", self.client)),
+        ];
+
+        let height = lines.len() as u16;
+        let item = Paragraph::new(lines).alignment(Alignment::Center);
+        let spot = Rect::new(area.x, area.y + area.height.saturating_sub(height) / 2, area.width, height);
+        frame.render_widget(item, spot);
     }
 
     fn scene(&mut self, frame: &mut Frame, area: Rect) {
@@ -235,28 +660,51 @@ impl Blackjack {
         let dealer_sum = Rect::new(dealer_area.x + 2, dealer_area.y + dealer_area.height.saturating_sub(1), dealer_area.width.saturating_sub(2), 1);
 
         let player_area = Rect::new(area.x, area.y + chunk, area.width, chunk);
-        let player_sum = Rect::new(player_area.x + 2, player_area.y, player_area.width.saturating_sub(2), 1);
 
-        let hide = self.phase == Phase::Player;
+        let playing = matches!(self.phase, Phase::Player | Phase::Switch);
+        let hidden: Vec<bool> = (0..self.dealer.cards.len()).map(|i| playing && i + self.rules.hidden >= 2).collect();
 
-        render_hand(frame, &self.dealer, dealer_area, hide);
-        if !hide {
-            render_sum(frame, "Dealer", self.dealer.worth(), dealer_sum);
+        render_mask(frame, &self.dealer, dealer_area, &hidden);
+        if !playing || self.rules.hidden == 0 {
+            render_sum(frame, "Dealer", self.dealer.total(), dealer_sum);
         }
 
-        render_hand(frame, &self.player, player_area, false);
-        render_sum(frame, "Player", self.player.worth(), player_sum);
+        let width = player_area.width / self.spots.len().max(1) as u16;
+        for (i, spot) in self.spots.iter().enumerate() {
+            let column = Rect::new(player_area.x + width * i as u16, player_area.y, width, player_area.height);
+            let sum = Rect::new(column.x + 2, column.y, column.width.saturating_sub(2), 1);
+
+            if self.phase == Phase::Player && i == self.active && self.spots.len() > 1 {
+                render_focus(frame, column, Color::Yellow);
+            }
+
+            render_mask(frame, &spot.hand, column, &[]);
+            let label = if spot.free.is_zero() { format!("Player ({})", spot.bet) } else { format!("Player ({} +{} free)", spot.bet, spot.free) };
+            render_sum(frame, &label, Total { natural: spot.natural(), ..spot.hand.total() }, sum);
+        }
     }
 }
 
 impl Play for Blackjack {
     fn handle(&mut self, key: KeyEvent) {
+        if key.code == KeyCode::Char('o') && !matches!(self.phase, Phase::Variant | Phase::Seed) {
+            self.overlay = !self.overlay;
+            return;
+        }
+
         match self.phase {
+            Phase::Variant => self.handle_variant(key),
+            Phase::Seed => self.handle_seed(key),
             Phase::Bet => self.handle_bet(key),
+            Phase::Switch => self.handle_switch(key),
             Phase::Player => self.handle_player(key),
             Phase::Result => self.handle_result(key),
             _ => {}
         }
+
+        if self.phase == Phase::End {
+            self.reveal();
+        }
     }
 
     fn render(&mut self, frame: &mut Frame, area: Rect) {
@@ -270,16 +718,33 @@ impl Play for Blackjack {
         self.help(frame, bottom);
 
         match self.phase {
-            Phase::Player | Phase::Dealer | Phase::Result => {
+            Phase::Variant => self.variants(frame, middle),
+            Phase::Seed => self.seed(frame, middle),
+            Phase::Switch | Phase::Player | Phase::Dealer | Phase::Result => {
                 self.scene(frame, middle);
             }
             _ => {}
         }
 
-        self.pop(frame, inner);
+        if self.overlay {
+            render_shoe(frame, &self.shoe, middle);
+        }
+
+        render_pop(frame, &self.message, inner);
     }
 
     fn active(&self) -> bool {
         self.phase != Phase::End
     }
+
+    fn chips(&mut self) -> Option<&mut Wallet> {
+        Some(&mut self.money)
+    }
+
+    fn prime(&mut self, cards: Vec<Card>) -> Result<(), String> {
+        let mut shoe = self.rules.shoe();
+        shoe.prime(cards)?;
+        self.shoe = shoe;
+        Ok(())
+    }
 }
@@ -1,5 +1,31 @@
-use core::fmt::{Display, Formatter, Result};
-use rand::{distr::StandardUniform, prelude::Distribution, seq::SliceRandom, Rng, RngExt};
+use core::{
+    cell::RefCell,
+    fmt::{Display, Formatter, Result},
+    str::FromStr,
+    sync::atomic::{AtomicU64, Ordering},
+};
+use rand::{distr::StandardUniform, prelude::Distribution, rngs::StdRng, seq::SliceRandom, Rng, RngExt, SeedableRng};
+
+use crate::shuffle;
+
+static SEED: AtomicU64 = AtomicU64::new(0);
+
+thread_local! {
+    static RNG: RefCell<StdRng> = RefCell::new(StdRng::seed_from_u64(seed()));
+}
+
+pub fn seed() -> u64 {
+    SEED.load(Ordering::Relaxed)
+}
+
+pub fn reseed(seed: u64) {
+    SEED.store(seed, Ordering::Relaxed);
+    RNG.with(|rng| *rng.borrow_mut() = StdRng::seed_from_u64(seed));
+}
+
+pub fn roll<T>(action: impl FnOnce(&mut StdRng) -> T) -> T {
+    RNG.with(|rng| action(&mut rng.borrow_mut()))
+}
 
 pub trait Worth {
     fn worth(&self) -> usize;
@@ -7,6 +33,8 @@ pub trait Worth {
 
 pub struct Shoe {
     pub cards: Vec<Card>,
+    pub dealt: Vec<Card>,
+    pub primed: Vec<Card>,
 }
 
 impl Shoe {
@@ -15,22 +43,124 @@ impl Shoe {
         for _ in 0..count {
             cards.extend(DECK.iter().copied());
         }
-        Shoe { cards }
+        Shoe { cards, dealt: Vec::new(), primed: Vec::new() }
+    }
+
+    pub fn jokers(count: u8, jokers: u8) -> Self {
+        let mut shoe = Shoe::new(count);
+        for _ in 0..jokers {
+            shoe.cards.push(Card::joker());
+        }
+        shoe
     }
 
     pub fn shuffle(&mut self) {
-        self.cards.shuffle(&mut rand::rng());
+        roll(|rng| self.shuffle_with(rng));
+    }
+
+    pub fn shuffle_with<R: Rng + ?Sized>(&mut self, rng: &mut R) {
+        self.cards.shuffle(rng);
+    }
+
+    pub fn riffle(&mut self, passes: usize) {
+        for _ in 0..passes {
+            roll(|rng| shuffle::riffle(&mut self.cards, rng));
+        }
+    }
+
+    pub fn strip(&mut self) {
+        roll(|rng| shuffle::strip(&mut self.cards, rng));
+    }
+
+    pub fn boxed(&mut self) {
+        roll(|rng| shuffle::boxed(&mut self.cards, rng));
+    }
+
+    pub fn gather(&mut self) {
+        self.cards.append(&mut self.dealt);
+    }
+
+    pub fn casino(&mut self) {
+        self.riffle(2);
+        self.strip();
+        self.riffle(1);
+        self.boxed();
+    }
+
+    pub fn seeded(count: u8, seed: u64) -> Self {
+        let mut shoe = Shoe::new(count);
+        shoe.shuffle_with(&mut StdRng::seed_from_u64(seed));
+        shoe
+    }
+
+    pub fn holds(&self, cards: &[Card]) -> core::result::Result<(), String> {
+        for (i, card) in cards.iter().enumerate() {
+            let need = cards[..=i].iter().filter(|item| *item == card).count();
+            if self.cards.iter().filter(|item| *item == card).count() < need {
+                return Err(format!("no {:#} left in the shoe", card));
+            }
+        }
+        Ok(())
+    }
+
+    pub fn prime(&mut self, mut cards: Vec<Card>) -> core::result::Result<(), String> {
+        self.holds(&cards)?;
+        cards.reverse();
+        self.primed = cards;
+        Ok(())
+    }
+
+    pub fn restock(&mut self, shoe: Shoe) {
+        let primed = core::mem::take(&mut self.primed);
+        *self = Shoe { primed, ..shoe };
     }
 
     pub fn draw(&mut self) -> Option<Card> {
-        self.cards.pop()
+        while let Some(card) = self.primed.pop() {
+            if let Some(index) = self.cards.iter().position(|item| *item == card) {
+                self.cards.remove(index);
+                self.dealt.push(card);
+                return Some(card);
+            }
+        }
+        let card = self.cards.pop();
+        self.dealt.extend(card);
+        card
     }
 
     pub fn remaining(&self) -> usize {
         self.cards.len()
     }
+
+    pub fn values(&self) -> [usize; 13] {
+        let mut counts = [0; 13];
+        for card in self.cards.iter().filter(|card| !card.is_joker()) {
+            counts[card.value.score() as usize - 2] += 1;
+        }
+        counts
+    }
+
+    pub fn count(&self, value: Value) -> usize {
+        self.cards.iter().filter(|card| card.value == value).count()
+    }
+
+    pub fn suits(&self) -> [usize; 4] {
+        let mut counts = [0; 4];
+        for card in self.cards.iter().filter(|card| !card.is_joker()) {
+            counts[card.suit as usize] += 1;
+        }
+        counts
+    }
+
+    pub fn decks(&self) -> f32 {
+        (self.cards.len() as f32 / 26.0).round() / 2.0
+    }
 }
 
+#[derive(Debug, Clone, PartialEq)]
+pub struct Invalid(pub String);
+
+#[derive(Clone)]
 pub struct Hand {
     pub cards: Vec<Card>,
 }
@@ -47,29 +177,68 @@ impl Hand {
 
 impl Worth for Hand {
     fn worth(&self) -> usize {
-        let mut total = 0;
-        let mut aces = 0;
+        self.total().sum
+    }
+}
 
-        for card in &self.cards {
-            if card.value == Value::Ace {
-                aces += 1;
-            } else {
-                total += card.worth();
-            }
-        }
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Total {
+    pub sum: usize,
+    pub soft: bool,
+    pub natural: bool,
+    pub bust: bool,
+    pub pair: bool,
+}
 
-        for _ in 0..aces {
-            if total + 11 <= 21 {
-                total += 11;
-            } else {
-                total += 1;
-            }
+impl Hand {
+    pub fn total(&self) -> Total {
+        let hard: usize = self.cards.iter().map(|card| card.worth()).sum();
+        let soft = self.cards.iter().any(|card| card.value == Value::Ace) && hard + 10 <= 21;
+        let sum = if soft { hard + 10 } else { hard };
+        let two = self.cards.len() == 2;
+
+        Total {
+            sum,
+            soft,
+            natural: two && sum == 21,
+            bust: sum > 21,
+            pair: two && self.cards[0].value == self.cards[1].value,
         }
+    }
+}
 
-        total
+impl Display for Total {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        if self.natural {
+            write!(f, "Blackjack")
+        } else if self.bust {
+            write!(f, "Bust {}", self.sum)
+        } else if self.soft {
+            write!(f, "Soft {}", self.sum)
+        } else {
+            write!(f, "{}", self.sum)
+        }
     }
 }
 
+pub const SUITS: [Suit; 4] = [Suit::Clubs, Suit::Diamonds, Suit::Hearts, Suit::Spades];
+
+pub const VALUES: [Value; 13] = [
+    Value::Two,
+    Value::Three,
+    Value::Four,
+    Value::Five,
+    Value::Six,
+    Value::Seven,
+    Value::Eight,
+    Value::Nine,
+    Value::Ten,
+    Value::Jack,
+    Value::Queen,
+    Value::King,
+    Value::Ace,
+];
+
 const DECK: [Card; 52] = [
     Card { suit: Suit::Clubs, value: Value::Two },
     Card { suit: Suit::Clubs, value: Value::Three },
@@ -146,6 +315,7 @@ pub enum Value {
     Queen,
     King,
     Ace,
+    Joker,
 }
 
 #[derive(Debug, Hash, Eq, PartialEq, Clone, Copy)]
@@ -172,10 +342,175 @@ impl Value {
             Value::Queen => 12,
             Value::King => 13,
             Value::Ace => 14,
+            Value::Joker => 15,
         }
     }
 }
 
+impl Value {
+    pub fn order(&self) -> u8 {
+        match self {
+            Value::Ace => 1,
+            other => other.score(),
+        }
+    }
+
+    pub fn pips(&self) -> u32 {
+        self.order().min(10) as u32
+    }
+
+    pub fn code(&self) -> &'static str {
+        match self {
+            Value::Two => "2",
+            Value::Three => "3",
+            Value::Four => "4",
+            Value::Five => "5",
+            Value::Six => "6",
+            Value::Seven => "7",
+            Value::Eight => "8",
+            Value::Nine => "9",
+            Value::Ten => "T",
+            Value::Jack => "J",
+            Value::Queen => "Q",
+            Value::King => "K",
+            Value::Ace => "A",
+            Value::Joker => "Jk",
+        }
+    }
+
+    pub fn parse(code: char) -> Option<Value> {
+        match code.to_ascii_uppercase() {
+            '2' => Some(Value::Two),
+            '3' => Some(Value::Three),
+            '4' => Some(Value::Four),
+            '5' => Some(Value::Five),
+            '6' => Some(Value::Six),
+            '7' => Some(Value::Seven),
+            '8' => Some(Value::Eight),
+            '9' => Some(Value::Nine),
+            'T' => Some(Value::Ten),
+            'J' => Some(Value::Jack),
+            'Q' => Some(Value::Queen),
+            'K' => Some(Value::King),
+            'A' => Some(Value::Ace),
+            _ => None,
+        }
+    }
+}
+
+impl Card {
+    pub fn joker() -> Self {
+        Card { suit: Suit::Spades, value: Value::Joker }
+    }
+
+    pub fn is_joker(&self) -> bool {
+        self.value == Value::Joker
+    }
+
+    pub fn is_wild(&self, wild: &[Value]) -> bool {
+        self.is_joker() || wild.contains(&self.value)
+    }
+}
+
+impl Suit {
+    pub fn red(&self) -> bool {
+        matches!(self, Suit::Hearts | Suit::Diamonds)
+    }
+
+    pub fn parse(code: char) -> Option<Suit> {
+        match code {
+            'c' | 'C' | '♣' => Some(Suit::Clubs),
+            'd' | 'D' | '♦' => Some(Suit::Diamonds),
+            'h' | 'H' | '♥' => Some(Suit::Hearts),
+            's' | 'S' | '♠' => Some(Suit::Spades),
+            _ => None,
+        }
+    }
+}
+
+impl Card {
+    fn split(text: &str) -> core::result::Result<(Card, &str), Invalid> {
+        let invalid = || Invalid(text.to_string());
+        if let Some(rest) = text.strip_prefix("Jk").or_else(|| text.strip_prefix("jk")) {
+            return Ok((Card::joker(), rest));
+        }
+
+        let (value, rest) = match text.strip_prefix("10") {
+            Some(rest) => (Value::Ten, rest),
+            None => {
+                let mut chars = text.chars();
+                let value = chars.next().and_then(Value::parse).ok_or_else(invalid)?;
+                (value, chars.as_str())
+            }
+        };
+
+        let mut chars = rest.chars();
+        let suit = chars.next().and_then(Suit::parse).ok_or_else(invalid)?;
+        Ok((Card { suit, value }, chars.as_str()))
+    }
+}
+
+impl FromStr for Card {
+    type Err = Invalid;
+
+    fn from_str(text: &str) -> core::result::Result<Self, Self::Err> {
+        match Card::split(text.trim())? {
+            (card, "") => Ok(card),
+            _ => Err(Invalid(text.to_string())),
+        }
+    }
+}
+
+impl FromStr for Hand {
+    type Err = Invalid;
+
+    fn from_str(text: &str) -> core::result::Result<Self, Self::Err> {
+        let mut hand = Hand::new();
+        for word in text.split_whitespace() {
+            let mut rest = word;
+            while !rest.is_empty() {
+                let (card, next) = Card::split(rest)?;
+                hand.add(card);
+                rest = next;
+            }
+        }
+        Ok(hand)
+    }
+}
+
+impl Display for Card {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        if self.is_joker() {
+            write!(f, "{}", self.value.code())
+        } else if f.alternate() {
+            write!(f, "{}{:#}", self.value.code(), self.suit)
+        } else {
+            write!(f, "{}{}", self.value.code(), self.suit)
+        }
+    }
+}
+
+impl Display for Hand {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        for card in &self.cards {
+            if f.alternate() {
+                write!(f, "{:#}", card)?;
+            } else {
+                write!(f, "{}", card)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Display for Invalid {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(f, "invalid card notation: {}", self.0)
+    }
+}
+
+impl std::error::Error for Invalid {}
+
 impl Display for Value {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result {
         match self {
@@ -192,12 +527,23 @@ impl Display for Value {
             Value::Jack => write!(f, "J"),
             Value::Queen => write!(f, "Q"),
             Value::King => write!(f, "K"),
+            Value::Joker => write!(f, "★"),
         }
     }
 }
 
 impl Display for Suit {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        if f.alternate() {
+            let code = match self {
+                Suit::Clubs => "c",
+                Suit::Diamonds => "d",
+                Suit::Hearts => "h",
+                Suit::Spades => "s",
+            };
+            return write!(f, "{}", code);
+        }
+
         match self {
             Suit::Clubs => write!(f, "♣"),
             Suit::Diamonds => write!(f, "♦"),
@@ -220,6 +566,7 @@ impl Worth for Card {
             Value::Eight => 8,
             Value::Nine => 9,
             Value::Ten | Value::Jack | Value::Queen | Value::King => 10,
+            Value::Joker => 0,
         }
     }
 }
@@ -265,3 +612,91 @@ impl Distribution<Suit> for StandardUniform {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn deal(shoe: &mut Shoe, count: usize) -> Vec<Card> {
+        (0..count).map(|_| shoe.draw().unwrap()).collect()
+    }
+
+    #[test]
+    fn seeded_shoe_repeats() {
+        assert_eq!(deal(&mut Shoe::seeded(1, 42), 52), deal(&mut Shoe::seeded(1, 42), 52));
+        assert_ne!(deal(&mut Shoe::seeded(1, 42), 52), deal(&mut Shoe::seeded(1, 43), 52));
+    }
+
+    #[test]
+    fn reseed_repeats_a_deal() {
+        let shuffled = |seed| {
+            reseed(seed);
+            let mut shoe = Shoe::new(1);
+            shoe.shuffle();
+            deal(&mut shoe, 10)
+        };
+        assert_eq!(shuffled(7), shuffled(7));
+    }
+
+    #[test]
+    fn seeded_deal() {
+        let hand = Hand { cards: deal(&mut Shoe::seeded(1, 1), 5) };
+        assert_eq!(format!("{:#}", hand), "5h2dThJh6h");
+    }
+
+    #[test]
+    fn primed_cards_come_first() {
+        let hand: Hand = "As Kd As".parse().unwrap();
+        let mut shoe = Shoe::seeded(1, 5);
+        assert!(shoe.prime(hand.cards.clone()).is_err());
+        assert!(shoe.prime(vec![Card::joker()]).is_err());
+
+        shoe.prime(hand.cards[..2].to_vec()).unwrap();
+        shoe.restock(Shoe::new(1));
+        assert_eq!(deal(&mut shoe, 2), hand.cards[..2]);
+        assert_eq!(shoe.remaining(), 50);
+        shoe.gather();
+        assert_eq!(shoe.remaining(), 52);
+    }
+
+    #[test]
+    fn card_round_trip() {
+        let card: Card = "As".parse().unwrap();
+        assert_eq!(card, Card { suit: Suit::Spades, value: Value::Ace });
+        assert_eq!(card.to_string(), "A♠");
+        assert_eq!(format!("{:#}", card), "As");
+        assert_eq!("A♠".parse::<Card>().unwrap(), card);
+        assert_eq!("10h".parse::<Card>().unwrap(), "Th".parse::<Card>().unwrap());
+        assert_eq!(format!("{:#}", "10h".parse::<Card>().unwrap()), "Th");
+    }
+
+    #[test]
+    fn joker_round_trip() {
+        let card: Card = "Jk".parse().unwrap();
+        assert!(card.is_joker());
+        assert_eq!(card.to_string(), "Jk");
+        assert_eq!(format!("{:#}", card), "Jk");
+        assert_eq!("jk".parse::<Card>().unwrap(), card);
+    }
+
+    #[test]
+    fn hand_round_trip() {
+        let hand: Hand = "As Kd 10c Jk 2h".parse().unwrap();
+        assert_eq!(format!("{:#}", hand), "AsKdTcJk2h");
+        assert_eq!(hand.to_string(), "A♠K♦T♣Jk2♥");
+        let again: Hand = format!("{:#}", hand).parse().unwrap();
+        assert_eq!(again.cards, hand.cards);
+        let again: Hand = hand.to_string().parse().unwrap();
+        assert_eq!(again.cards, hand.cards);
+    }
+
+    #[test]
+    fn rejects_bad_cards() {
+        for text in ["1x", "A", "", "Ax", "Zs", "Ass", "11h"] {
+            assert!(text.parse::<Card>().is_err(), "{}", text);
+        }
+        for text in ["As 1x", "A", "Kd Q"] {
+            assert!(text.parse::<Hand>().is_err(), "{}", text);
+        }
+    }
+}
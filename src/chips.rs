@@ -0,0 +1,138 @@
+use core::fmt::{Display, Formatter, Result};
+
+pub const STEP: Chips = Chips::dollars(10);
+pub const BANKROLL: Chips = Chips::dollars(1000);
+pub const HOUSE: Chips = Chips::dollars(100_000);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Chips(u64);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Error {
+    Overflow,
+    Short { need: Chips, have: Chips },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Round {
+    Down,
+    Up,
+    Nearest,
+}
+
+impl Chips {
+    pub const ZERO: Chips = Chips(0);
+
+    pub const fn dollars(amount: u64) -> Chips {
+        Chips(amount * 100)
+    }
+
+    pub const fn cents(amount: u64) -> Chips {
+        Chips(amount)
+    }
+
+    pub fn total(&self) -> u64 {
+        self.0
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.0 == 0
+    }
+
+    pub fn plus(self, other: Chips) -> core::result::Result<Chips, Error> {
+        self.0.checked_add(other.0).map(Chips).ok_or(Error::Overflow)
+    }
+
+    pub fn minus(self, other: Chips) -> core::result::Result<Chips, Error> {
+        self.0.checked_sub(other.0).map(Chips).ok_or(Error::Short { need: other, have: self })
+    }
+
+    pub fn times(self, factor: u64) -> core::result::Result<Chips, Error> {
+        self.0.checked_mul(factor).map(Chips).ok_or(Error::Overflow)
+    }
+
+    pub fn ratio(self, num: u64, den: u64, round: Round) -> core::result::Result<Chips, Error> {
+        let whole = self.0 as u128 * num as u128;
+        let den = den as u128;
+        let cents = match round {
+            Round::Down => whole / den,
+            Round::Up => whole.div_ceil(den),
+            Round::Nearest => (whole + den / 2) / den,
+        };
+        u64::try_from(cents).map(Chips).map_err(|_| Error::Overflow)
+    }
+
+    pub fn split(self, parts: u64) -> (Chips, Chips) {
+        let share = self.0 / parts.max(1);
+        (Chips(share), Chips(self.0 - share * parts.max(1)))
+    }
+
+    pub fn raise(self, step: Chips, limit: Chips) -> Chips {
+        Chips(self.0.saturating_add(step.0).min(limit.0))
+    }
+
+    pub fn lower(self, step: Chips, floor: Chips) -> Chips {
+        Chips(self.0.saturating_sub(step.0).max(floor.0))
+    }
+}
+
+pub fn net(paid: Chips, staked: Chips) -> String {
+    match paid.minus(staked) {
+        Ok(gain) => format!("+{}", gain),
+        Err(_) => format!("-{}", Chips(staked.0 - paid.0)),
+    }
+}
+
+impl Display for Chips {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self.0 % 100 {
+            0 => f.pad(&format!("${}", self.0 / 100)),
+            cents => f.pad(&format!("${}.{:02}", self.0 / 100, cents)),
+        }
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            Error::Overflow => write!(f, "Amount too large"),
+            Error::Short { need, have } => write!(f, "Need {}, have {}", need, have),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+pub struct Wallet {
+    balance: Chips,
+}
+
+impl Wallet {
+    pub fn new(balance: Chips) -> Self {
+        Self { balance }
+    }
+
+    pub fn balance(&self) -> Chips {
+        self.balance
+    }
+
+    pub fn covers(&self, amount: Chips) -> bool {
+        amount <= self.balance
+    }
+
+    pub fn broke(&self) -> bool {
+        self.balance.is_zero()
+    }
+
+    fn debit(&mut self, amount: Chips) -> core::result::Result<(), Error> {
+        self.balance = self.balance.minus(amount)?;
+        Ok(())
+    }
+
+    pub fn transfer(&mut self, to: &mut Wallet, amount: Chips) -> core::result::Result<(), Error> {
+        let funds = to.balance.plus(amount)?;
+        self.debit(amount)?;
+        to.balance = funds;
+        Ok(())
+    }
+}
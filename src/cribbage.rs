@@ -0,0 +1,628 @@
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::{
+    layout::{Margin, Rect},
+    style::{Color, Style},
+    text::{Line, Span},
+    Frame,
+};
+
+use crate::{
+    card::{Card, Hand, Shoe, Value},
+    play::Play,
+    poker::subsets,
+    set::CardSet,
+    show::{render_backs, render_card, render_hand, render_help, render_pick, render_pop, render_slot, render_status},
+};
+
+const NAMES: [&str; 2] = ["You", "Bot"];
+const GOAL: u32 = 121;
+
+#[derive(PartialEq)]
+pub enum Phase {
+    Discard,
+    Peg,
+    Show,
+    Over,
+    End,
+}
+
+pub struct Combo {
+    pub name: String,
+    pub points: u32,
+}
+
+pub struct Cribbage {
+    pub phase: Phase,
+    pub shoe: Shoe,
+    pub hands: [Hand; 2],
+    pub left: [Vec<Card>; 2],
+    pub crib: Hand,
+    pub starter: Option<Card>,
+    pub dealer: usize,
+    pub scores: [u32; 2],
+    pub backs: [u32; 2],
+    pub pile: Vec<Card>,
+    pub total: u32,
+    pub turn: usize,
+    pub last: usize,
+    pub log: Vec<String>,
+    pub shows: Vec<(usize, String, Vec<Combo>)>,
+    pub step: usize,
+    pub cursor: usize,
+    pub marks: Vec<bool>,
+    pub message: String,
+}
+
+impl Cribbage {
+    pub fn new() -> Self {
+        let mut game = Self {
+            phase: Phase::Discard,
+            shoe: Shoe::new(1),
+            hands: [Hand::new(), Hand::new()],
+            left: [Vec::new(), Vec::new()],
+            crib: Hand::new(),
+            starter: None,
+            dealer: 1,
+            scores: [0; 2],
+            backs: [0; 2],
+            pile: Vec::new(),
+            total: 0,
+            turn: 0,
+            last: 0,
+            log: Vec::new(),
+            shows: Vec::new(),
+            step: 0,
+            cursor: 0,
+            marks: Vec::new(),
+            message: String::new(),
+        };
+        game.deal();
+        game
+    }
+
+    fn deal(&mut self) {
+        self.shoe.restock(Shoe::new(1));
+        self.shoe.shuffle();
+
+        self.dealer = 1 - self.dealer;
+        self.hands = [Hand::new(), Hand::new()];
+        self.crib = Hand::new();
+        for _ in 0..6 {
+            for hand in &mut self.hands {
+                hand.add(self.shoe.draw().unwrap());
+            }
+        }
+        for hand in &mut self.hands {
+            hand.cards.sort_by_key(|card| card.value.order());
+        }
+
+        self.starter = self.shoe.draw();
+        self.pile.clear();
+        self.total = 0;
+        self.log.clear();
+        self.shows.clear();
+        self.step = 0;
+        self.cursor = 0;
+        self.marks = vec![false; 6];
+        self.message.clear();
+        self.phase = Phase::Discard;
+    }
+
+    fn gain(&mut self, seat: usize, points: u32, reason: &str) {
+        if points == 0 || self.phase == Phase::Over {
+            return;
+        }
+        self.backs[seat] = self.scores[seat];
+        self.scores[seat] = (self.scores[seat] + points).min(GOAL);
+        self.log.push(format!("{}: {} for {}", NAMES[seat], reason, points));
+
+        if self.scores[seat] >= GOAL {
+            self.message = format!("{} wins {} to {}", NAMES[seat], self.scores[seat], self.scores[1 - seat]);
+            self.phase = Phase::Over;
+        }
+    }
+
+    fn discard(&mut self) {
+        let picks: Vec<usize> = (0..6).filter(|&i| self.marks[i]).collect();
+        let bot = keeps(&self.hands[1].cards, self.dealer == 1);
+
+        for (seat, pick) in [(0, picks), (1, bot)] {
+            for &i in pick.iter().rev() {
+                let card = self.hands[seat].cards.remove(i);
+                self.crib.add(card);
+            }
+        }
+
+        self.left = [self.hands[0].cards.clone(), self.hands[1].cards.clone()];
+        self.phase = Phase::Peg;
+
+        let starter = self.starter.unwrap();
+        if starter.value == Value::Jack {
+            self.gain(self.dealer, 2, "His heels");
+        }
+
+        self.turn = 1 - self.dealer;
+        self.advance();
+    }
+
+    fn can(&self, seat: usize) -> bool {
+        self.left[seat].iter().any(|card| self.total + pips(card) <= 31)
+    }
+
+    fn place(&mut self, seat: usize, index: usize) {
+        let card = self.left[seat].remove(index);
+        self.pile.push(card);
+        self.total += pips(&card);
+        self.last = seat;
+
+        for combo in peg(&self.pile) {
+            self.gain(seat, combo.points, &combo.name);
+        }
+
+        if self.total == 31 {
+            self.pile.clear();
+            self.total = 0;
+        }
+        self.turn = 1 - seat;
+    }
+
+    fn advance(&mut self) {
+        while self.phase == Phase::Peg {
+            if self.left.iter().all(|cards| cards.is_empty()) {
+                if self.total > 0 {
+                    self.gain(self.last, 1, "Last card");
+                }
+                if self.phase == Phase::Over {
+                    return;
+                }
+                self.show();
+                return;
+            }
+
+            if self.can(self.turn) {
+                if self.turn == 0 {
+                    return;
+                }
+                let index = choose(&self.left[1], &self.pile, self.total);
+                self.place(1, index);
+            } else if self.can(1 - self.turn) {
+                self.log.push(format!("{}: Go", NAMES[self.turn]));
+                self.turn = 1 - self.turn;
+            } else {
+                self.gain(self.last, 1, "Go");
+                self.pile.clear();
+                self.total = 0;
+                self.turn = 1 - self.last;
+            }
+        }
+    }
+
+    fn show(&mut self) {
+        let starter = self.starter.unwrap();
+        let pone = 1 - self.dealer;
+        self.shows = vec![
+            (pone, format!("{} hand", NAMES[pone]), count(&self.hands[pone].cards, starter, false)),
+            (self.dealer, format!("{} hand", NAMES[self.dealer]), count(&self.hands[self.dealer].cards, starter, false)),
+            (self.dealer, format!("{} crib", NAMES[self.dealer]), count(&self.crib.cards, starter, true)),
+        ];
+        self.step = 0;
+        self.total = 0;
+        self.phase = Phase::Show;
+        self.score_show();
+    }
+
+    fn score_show(&mut self) {
+        let (seat, title, combos) = &self.shows[self.step];
+        let (seat, points) = (*seat, combos.iter().map(|combo| combo.points).sum());
+        let title = title.clone();
+        self.gain(seat, points, &title);
+    }
+
+    fn handle_discard(&mut self, key: KeyEvent) {
+        match key.code {
+            KeyCode::Char('q') => self.phase = Phase::End,
+            KeyCode::Left | KeyCode::Char('a') => self.cursor = self.cursor.saturating_sub(1),
+            KeyCode::Right | KeyCode::Char('d') => self.cursor = (self.cursor + 1).min(5),
+            KeyCode::Char(' ') => {
+                let count = self.marks.iter().filter(|&&mark| mark).count();
+                if self.marks[self.cursor] || count < 2 {
+                    self.marks[self.cursor] = !self.marks[self.cursor];
+                }
+            }
+            KeyCode::Enter if self.marks.iter().filter(|&&mark| mark).count() == 2 => {
+                self.cursor = 0;
+                self.discard();
+            }
+            _ => {}
+        }
+    }
+
+    fn handle_peg(&mut self, key: KeyEvent) {
+        let last = self.left[0].len().saturating_sub(1);
+        match key.code {
+            KeyCode::Char('q') => self.phase = Phase::End,
+            KeyCode::Left | KeyCode::Char('a') => self.cursor = self.cursor.saturating_sub(1),
+            KeyCode::Right | KeyCode::Char('d') => self.cursor = (self.cursor + 1).min(last),
+            KeyCode::Enter | KeyCode::Char(' ') => {
+                let Some(card) = self.left[0].get(self.cursor) else {
+                    return;
+                };
+                if self.total + pips(card) > 31 {
+                    self.message = "That would go past 31".into();
+                    return;
+                }
+                self.message.clear();
+                self.place(0, self.cursor);
+                self.cursor = self.cursor.min(self.left[0].len().saturating_sub(1));
+                self.advance();
+            }
+            _ => {}
+        }
+    }
+
+    fn handle_show(&mut self, key: KeyEvent) {
+        match key.code {
+            KeyCode::Char('q') => self.phase = Phase::End,
+            KeyCode::Enter => {
+                if self.step + 1 < self.shows.len() {
+                    self.step += 1;
+                    self.score_show();
+                } else {
+                    self.deal();
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn handle_over(&mut self, key: KeyEvent) {
+        match key.code {
+            KeyCode::Char('q') => self.phase = Phase::End,
+            KeyCode::Enter => {
+                self.scores = [0; 2];
+                self.backs = [0; 2];
+                self.deal();
+            }
+            _ => {}
+        }
+    }
+
+    fn status(&self, frame: &mut Frame, area: Rect) {
+        let lines = [
+            Line::raw(format!("You: {}  Bot: {}", self.scores[0], self.scores[1])),
+            Line::raw(format!("Dealer: {}", NAMES[self.dealer])),
+            Line::raw(format!("Count: {}", self.total)),
+        ];
+        render_status(frame, &lines, area);
+    }
+
+    fn help(&self, frame: &mut Frame, area: Rect) {
+        let lines = match self.phase {
+            Phase::Discard => vec![
+                Line::raw("[←→] Move"),
+                Line::raw("[Space] Mark two for the crib"),
+                Line::raw("[Enter] Discard"),
+                Line::raw("[Q] Quit"),
+            ],
+            Phase::Peg => vec![
+                Line::raw("[←→] Move"),
+                Line::raw("[Enter] Play"),
+                Line::raw("[Q] Quit"),
+            ],
+            Phase::Show => vec![
+                Line::raw("[Enter] Next"),
+                Line::raw("[Q] Quit"),
+            ],
+            Phase::Over => vec![
+                Line::raw("[Enter] New game"),
+                Line::raw("[Q] Quit"),
+            ],
+            _ => vec![Line::raw("")],
+        };
+        render_help(frame, &lines, area);
+    }
+
+    fn track(&self, frame: &mut Frame, area: Rect) {
+        let tints = [Color::Yellow, Color::Cyan];
+        for seat in 0..2 {
+            let mut spans = vec![Span::raw(format!("{:<4}", NAMES[seat]))];
+            for hole in 1..=GOAL {
+                let mark = if hole == self.scores[seat] {
+                    "●"
+                } else if hole == self.backs[seat] {
+                    "○"
+                } else if hole % 30 == 0 {
+                    "|"
+                } else if hole % 5 == 0 {
+                    ":"
+                } else {
+                    "·"
+                };
+                let style = if hole <= self.scores[seat] { Style::default().fg(tints[seat]) } else { Style::default().fg(Color::DarkGray) };
+                spans.push(Span::styled(mark, style));
+            }
+            let row = Rect::new(area.x + 2, area.y + seat as u16, area.width.saturating_sub(2), 1);
+            frame.render_widget(Line::from(spans), row);
+        }
+    }
+
+    fn scene(&mut self, frame: &mut Frame, area: Rect) {
+        self.track(frame, Rect::new(area.x, area.y, area.width, 2));
+
+        let body = Rect::new(area.x, area.y + 3, area.width, area.height.saturating_sub(3));
+        let chunk = body.height / 3;
+        let bot = Rect::new(body.x, body.y, body.width, chunk);
+        let table = Rect::new(body.x, body.y + chunk, body.width, chunk);
+        let mine = Rect::new(body.x, body.y + chunk * 2, body.width, chunk);
+
+        let starter = Rect::new(table.x + 2, table.y + table.height.saturating_sub(7) / 2, 10, 7.min(table.height));
+        match (self.phase == Phase::Discard, self.starter) {
+            (false, Some(card)) => render_card(frame, &card, starter),
+            _ => render_slot(frame, "Cut", starter),
+        }
+
+        let pile = Rect::new(table.x + 14, table.y, table.width.saturating_sub(14).min(60), table.height);
+        let side = Rect::new(pile.x + pile.width + 2, table.y, table.width.saturating_sub(pile.width + 18), table.height);
+
+        if self.phase == Phase::Show {
+            let (seat, title, combos) = &self.shows[self.step];
+            let cards = if self.step == 2 { &self.crib } else { &self.hands[*seat] };
+            render_hand(frame, cards, pile, false);
+
+            let mut lines = vec![Line::raw(title.clone())];
+            for combo in combos {
+                lines.push(Line::raw(format!("{} for {}", combo.name, combo.points)));
+            }
+            lines.push(Line::raw(format!("Total {}", combos.iter().map(|combo| combo.points).sum::<u32>())));
+            render_status(frame, &lines, side);
+
+            render_hand(frame, &self.hands[1 - *seat], if *seat == 0 { bot } else { mine }, false);
+            return;
+        }
+
+        let pile_hand = Hand { cards: self.pile.clone() };
+        render_hand(frame, &pile_hand, pile, false);
+
+        let recent: Vec<Line> = self.log.iter().rev().take(side.height as usize).rev().map(|entry| Line::raw(entry.clone())).collect();
+        render_status(frame, &recent, side);
+
+        let count = if self.phase == Phase::Discard { self.hands[1].cards.len() } else { self.left[1].len() };
+        render_backs(frame, count, bot);
+
+        let cards = if self.phase == Phase::Discard { self.hands[0].clone() } else { Hand { cards: self.left[0].clone() } };
+        let marks = if self.phase == Phase::Discard { self.marks.clone() } else { Vec::new() };
+        render_pick(frame, &cards, mine, self.cursor, &marks);
+    }
+}
+
+fn pips(card: &Card) -> u32 {
+    card.value.pips()
+}
+
+fn label(cards: &[Card]) -> String {
+    cards.iter().map(|card| format!("{}{}", card.value, card.suit)).collect::<Vec<String>>().join(" ")
+}
+
+fn is_run(cards: &[Card]) -> bool {
+    let mut orders: Vec<u8> = cards.iter().map(|card| card.value.order()).collect();
+    orders.sort();
+    orders.windows(2).all(|pair| pair[1] == pair[0] + 1)
+}
+
+pub fn count(hand: &[Card], starter: Card, crib: bool) -> Vec<Combo> {
+    let mut all = hand.to_vec();
+    all.push(starter);
+    let mut combos = Vec::new();
+
+    for size in 2..=all.len() {
+        for set in subsets(&all, size) {
+            if set.iter().map(pips).sum::<u32>() == 15 {
+                combos.push(Combo { name: format!("Fifteen {}", label(&set)), points: 2 });
+            }
+        }
+    }
+
+    for pair in subsets(&all, 2) {
+        if pair[0].value == pair[1].value {
+            combos.push(Combo { name: format!("Pair {}", label(&pair)), points: 2 });
+        }
+    }
+
+    for size in (3..=all.len()).rev() {
+        let runs: Vec<Vec<Card>> = subsets(&all, size).into_iter().filter(|set| is_run(set)).collect();
+        if !runs.is_empty() {
+            for run in runs {
+                combos.push(Combo { name: format!("Run {}", label(&run)), points: size as u32 });
+            }
+            break;
+        }
+    }
+
+    if hand.iter().all(|card| card.suit == hand[0].suit) {
+        if starter.suit == hand[0].suit {
+            combos.push(Combo { name: "Flush of five".into(), points: 5 });
+        } else if !crib {
+            combos.push(Combo { name: "Flush".into(), points: 4 });
+        }
+    }
+
+    if hand.iter().any(|card| card.value == Value::Jack && card.suit == starter.suit) {
+        combos.push(Combo { name: "His nobs".into(), points: 1 });
+    }
+
+    combos
+}
+
+pub fn peg(pile: &[Card]) -> Vec<Combo> {
+    let mut combos = Vec::new();
+    let total: u32 = pile.iter().map(pips).sum();
+
+    if total == 15 {
+        combos.push(Combo { name: "Fifteen".into(), points: 2 });
+    }
+    if total == 31 {
+        combos.push(Combo { name: "Thirty-one".into(), points: 2 });
+    }
+
+    let last = pile[pile.len() - 1].value;
+    let same = pile.iter().rev().take_while(|card| card.value == last).count();
+    match same {
+        2 => combos.push(Combo { name: "Pair".into(), points: 2 }),
+        3 => combos.push(Combo { name: "Pair royal".into(), points: 6 }),
+        4 => combos.push(Combo { name: "Double pair royal".into(), points: 12 }),
+        _ => {}
+    }
+
+    if let Some(size) = (3..=pile.len()).rev().find(|&size| is_run(&pile[pile.len() - size..])) {
+        combos.push(Combo { name: format!("Run of {}", size), points: size as u32 });
+    }
+
+    combos
+}
+
+fn keeps(cards: &[Card], own: bool) -> Vec<usize> {
+    let mut best = (i64::MIN, vec![0, 1]);
+
+    for a in 0..cards.len() {
+        for b in a + 1..cards.len() {
+            let keep: Vec<Card> = (0..cards.len()).filter(|&i| i != a && i != b).map(|i| cards[i]).collect();
+            let crib = peg_value(&[cards[a], cards[b]]);
+
+            let mut value = 0i64;
+            for starter in !CardSet::from(cards) {
+                value += count(&keep, starter, false).iter().map(|combo| combo.points as i64).sum::<i64>();
+                value += if own { crib } else { -crib };
+            }
+
+            if value > best.0 {
+                best = (value, vec![a, b]);
+            }
+        }
+    }
+
+    best.1
+}
+
+fn peg_value(thrown: &[Card]) -> i64 {
+    let mut value = 0;
+    if thrown[0].value == thrown[1].value {
+        value += 2;
+    }
+    if pips(&thrown[0]) + pips(&thrown[1]) == 15 {
+        value += 2;
+    }
+    if thrown.iter().any(|card| card.value == Value::Five) {
+        value += 1;
+    }
+    value
+}
+
+fn choose(cards: &[Card], pile: &[Card], total: u32) -> usize {
+    let mut best = (i64::MIN, 0);
+    for (i, card) in cards.iter().enumerate() {
+        let next = total + pips(card);
+        if next > 31 {
+            continue;
+        }
+        let mut trial = pile.to_vec();
+        trial.push(*card);
+        let mut value = peg(&trial).iter().map(|combo| combo.points as i64 * 10).sum::<i64>();
+        if next == 5 || next == 21 {
+            value -= 5;
+        }
+        value += pips(card) as i64;
+        if value > best.0 {
+            best = (value, i);
+        }
+    }
+    best.1
+}
+
+impl Play for Cribbage {
+    fn handle(&mut self, key: KeyEvent) {
+        match self.phase {
+            Phase::Discard => self.handle_discard(key),
+            Phase::Peg => self.handle_peg(key),
+            Phase::Show => self.handle_show(key),
+            Phase::Over => self.handle_over(key),
+            _ => {}
+        }
+    }
+
+    fn render(&mut self, frame: &mut Frame, area: Rect) {
+        let inner = area.inner(Margin::new(1, 1));
+
+        let top = Rect::new(inner.x, inner.y, inner.width, 3);
+        let bottom = Rect::new(inner.x, inner.y + inner.height.saturating_sub(4), inner.width, 4);
+        let middle = Rect::new(inner.x, inner.y + 3, inner.width, inner.height.saturating_sub(7));
+
+        self.status(frame, top);
+        self.help(frame, bottom);
+        self.scene(frame, middle);
+
+        render_pop(frame, &self.message, inner);
+    }
+
+    fn active(&self) -> bool {
+        self.phase != Phase::End
+    }
+
+    fn prime(&mut self, cards: Vec<Card>) -> Result<(), String> {
+        let mut shoe = Shoe::new(1);
+        shoe.prime(cards)?;
+        self.shoe = shoe;
+        self.dealer = 1 - self.dealer;
+        self.deal();
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn counted(hand: &str, starter: &str, crib: bool) -> Vec<Combo> {
+        count(&hand.parse::<Hand>().unwrap().cards, starter.parse().unwrap(), crib)
+    }
+
+    fn pegged(pile: &str) -> u32 {
+        peg(&pile.parse::<Hand>().unwrap().cards).iter().map(|combo| combo.points).sum()
+    }
+
+    fn total(combos: &[Combo]) -> u32 {
+        combos.iter().map(|combo| combo.points).sum()
+    }
+
+    #[test]
+    fn count_finds_the_twenty_nine_hand() {
+        assert_eq!(total(&counted("5h 5d 5c Js", "5s", false)), 29);
+    }
+
+    #[test]
+    fn count_scores_his_nobs() {
+        let nobs = |combos: &[Combo]| combos.iter().any(|combo| combo.name == "His nobs");
+        assert!(nobs(&counted("Jd 2c 4h 9s", "Kd", false)));
+        assert!(!nobs(&counted("Jd 2c 4h 9s", "Kc", false)));
+        assert!(!nobs(&counted("Kd 2c 4h 9s", "Jd", false)));
+    }
+
+    #[test]
+    fn crib_flush_needs_five_cards() {
+        assert_eq!(total(&counted("2h 4h 6h 8h", "Ts", false)), 4);
+        assert_eq!(total(&counted("2h 4h 6h 8h", "Ts", true)), 0);
+        assert_eq!(total(&counted("2h 4h 6h 8h", "Th", true)), 5);
+    }
+
+    #[test]
+    fn peg_scores_pairs_runs_and_thirty_one() {
+        assert_eq!(pegged("5h Tc"), 2);
+        assert_eq!(pegged("9c 9d"), 2);
+        assert_eq!(pegged("7h 7d 7c"), 6);
+        assert_eq!(pegged("2c 2d 2h 2s"), 12);
+        assert_eq!(pegged("2c 4d 3h"), 3);
+        assert_eq!(pegged("Kd 6h 4c 5s 3d"), 4);
+        assert_eq!(pegged("3c 4d 4h 5s"), 0);
+        assert_eq!(pegged("Kd 6h 4c 5s 6d"), 5);
+        assert_eq!(pegged("Tc 7d 7h 7s"), 8);
+    }
+}
@@ -0,0 +1,457 @@
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::{
+    layout::{Margin, Rect},
+    text::Line,
+    Frame,
+};
+
+use crate::{
+    card::{Card, Hand, Shoe, Suit, Value, SUITS},
+    chips::{Error, Wallet},
+    play::Play,
+    poker::{deuce_seven, evaluate, Rank},
+    show::{render_backs, render_hand, render_help, render_pick, render_pop, render_status},
+    table::{Round, Seat, Step, Table},
+};
+
+#[derive(PartialEq)]
+pub enum Phase {
+    Ante,
+    Bet,
+    Swap,
+    Result,
+    End,
+}
+
+#[derive(Clone, Copy)]
+pub struct Rules {
+    pub draws: u8,
+    pub low: bool,
+}
+
+pub const FIVE: Rules = Rules {
+    draws: 1,
+    low: false,
+};
+
+pub const TRIPLE: Rules = Rules {
+    draws: 3,
+    low: true,
+};
+
+pub struct Draw {
+    pub phase: Phase,
+    pub rules: Rules,
+    pub drawn: u8,
+    pub shoe: Shoe,
+    pub table: Table,
+    pub button: usize,
+    pub cursor: usize,
+    pub marks: Vec<bool>,
+    pub muck: Vec<Card>,
+    pub message: String,
+}
+
+impl Draw {
+    pub fn new(rules: Rules) -> Self {
+        let seats = vec![
+            Seat::new("You"),
+            Seat::new("Bot 1"),
+            Seat::new("Bot 2"),
+            Seat::new("Bot 3"),
+        ];
+        Self {
+            phase: Phase::Ante,
+            rules,
+            drawn: 0,
+            shoe: Shoe::new(1),
+            table: Table::new(seats),
+            button: 0,
+            cursor: 0,
+            marks: vec![false; 5],
+            muck: Vec::new(),
+            message: String::new(),
+        }
+    }
+
+    fn deal(&mut self) -> Result<(), Error> {
+        self.message.clear();
+        self.table.reset();
+        self.shoe.restock(Shoe::new(1));
+        self.shoe.shuffle();
+        self.button = (self.button + 1) % self.table.seats.len();
+        self.cursor = 0;
+        self.marks = vec![false; 5];
+        self.muck.clear();
+        self.drawn = 0;
+
+        let bet = self.table.bet;
+        self.table.refill(bet.times(4)?)?;
+        self.table.ante(bet)?;
+
+        for _ in 0..5 {
+            for seat in &mut self.table.seats {
+                seat.hand.add(self.shoe.draw().unwrap());
+            }
+        }
+
+        self.table.open(self.table.next(self.button), bet);
+        self.phase = Phase::Bet;
+        self.flow()
+    }
+
+    fn flow(&mut self) -> Result<(), Error> {
+        loop {
+            let (low, left) = (self.rules.low, self.drawn < self.rules.draws);
+            match self.table.walk(|hand| strength(hand, low, left))? {
+                Round::Wait => return Ok(()),
+                Round::Over => return self.showdown(),
+                Round::Done => {}
+            }
+
+            if !left {
+                return self.showdown();
+            }
+
+            for index in 1..self.table.seats.len() {
+                if !self.table.seats[index].folded {
+                    let hand = &self.table.seats[index].hand;
+                    let picks = if low { lowball(hand) } else { discards(hand) };
+                    self.replace(index, &picks);
+                }
+            }
+            self.phase = Phase::Swap;
+            if !self.table.seats[0].folded {
+                return Ok(());
+            }
+            self.round()?;
+        }
+    }
+
+    fn round(&mut self) -> Result<(), Error> {
+        self.drawn += 1;
+        let bet = self.table.bet;
+        let size = if self.drawn * 2 <= self.rules.draws { bet } else { bet.times(2)? };
+        self.table.open(self.table.next(self.button), size);
+        self.phase = Phase::Bet;
+        Ok(())
+    }
+
+    fn rank(&self, hand: &Hand) -> String {
+        if self.rules.low { deuce_seven(&hand.cards).to_string() } else { evaluate(hand, &Hand::new()).rank.to_string() }
+    }
+
+    fn replace(&mut self, index: usize, picks: &[usize]) {
+        let seat = &mut self.table.seats[index];
+        let mut discards = Vec::new();
+        for &pick in picks {
+            if self.shoe.remaining() == 0 {
+                self.shoe.cards.append(&mut self.muck);
+                self.shoe.shuffle();
+            }
+            discards.push(seat.hand.cards[pick]);
+            seat.hand.cards[pick] = self.shoe.draw().unwrap();
+        }
+        self.muck.append(&mut discards);
+        seat.last = match picks.len() {
+            0 => "Stand pat".into(),
+            count => format!("Draw {}", count),
+        };
+    }
+
+    fn showdown(&mut self) -> Result<(), Error> {
+        self.phase = Phase::Result;
+        if let Some(text) = self.table.take()? {
+            self.message = text;
+            return Ok(());
+        }
+
+        let live = self.table.players();
+        let winners = if self.rules.low {
+            self.table.best(&live, |hand| deuce_seven(&hand.cards))
+        } else {
+            self.table.best(&live, |hand| evaluate(hand, &Hand::new()))
+        };
+        let best = self.rank(&self.table.seats[winners[0]].hand);
+
+        self.message = if winners.len() > 1 {
+            format!("Split pot with {}", best)
+        } else {
+            format!("{} wins with {}", self.table.seats[winners[0]].name, best)
+        };
+
+        self.table.award(&winners)
+    }
+
+    fn handle_ante(&mut self, key: KeyEvent) {
+        match self.table.wager(key) {
+            Step::Quit => self.phase = Phase::End,
+            Step::Deal => {
+                if let Err(error) = self.deal() {
+                    self.message = error.to_string();
+                }
+            }
+            Step::Short(text) => self.message = text,
+            _ => {}
+        }
+    }
+
+    fn handle_action(&mut self, key: KeyEvent) {
+        match self.table.respond(key) {
+            Step::Acted => {
+                if let Err(error) = self.flow() {
+                    self.message = error.to_string();
+                }
+            }
+            Step::Short(text) => self.message = text,
+            _ => {}
+        }
+    }
+
+    fn handle_swap(&mut self, key: KeyEvent) {
+        match key.code {
+            KeyCode::Left | KeyCode::Char('a') => {
+                self.cursor = self.cursor.saturating_sub(1);
+            }
+            KeyCode::Right | KeyCode::Char('d') => {
+                self.cursor = (self.cursor + 1).min(4);
+            }
+            KeyCode::Char(' ') => {
+                self.marks[self.cursor] = !self.marks[self.cursor];
+            }
+            KeyCode::Enter => {
+                let picks: Vec<usize> = (0..5).filter(|&i| self.marks[i]).collect();
+                let hand = &self.table.seats[0].hand;
+                let ace = (0..5).any(|i| !self.marks[i] && hand.cards[i].value == Value::Ace);
+
+                if !self.rules.low && (picks.len() > 4 || (picks.len() == 4 && !ace)) {
+                    self.message = "Draw up to 3, or 4 keeping an ace".into();
+                    return;
+                }
+
+                self.message.clear();
+                self.replace(0, &picks);
+                self.marks = vec![false; 5];
+                if let Err(error) = self.round().and_then(|()| self.flow()) {
+                    self.message = error.to_string();
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn handle_result(&mut self, key: KeyEvent) {
+        match self.table.rebet(key) {
+            Step::Quit => self.phase = Phase::End,
+            Step::Next => {
+                self.message.clear();
+                self.phase = Phase::Ante;
+            }
+            _ => {}
+        }
+    }
+
+    fn status(&self, frame: &mut Frame, area: Rect) {
+        render_status(frame, &self.table.status(), area);
+    }
+
+    fn help(&self, frame: &mut Frame, area: Rect) {
+        let lines = match self.phase {
+            Phase::Ante => vec![
+                Line::raw("[↑↓] Bet"),
+                Line::raw("[Enter] Deal"),
+                Line::raw("[Q] Quit"),
+            ],
+            Phase::Bet => self.table.prompt(),
+            Phase::Swap => vec![
+                Line::raw("[←→] Move"),
+                Line::raw("[Space] Mark discard"),
+                Line::raw(format!("[Enter] Draw {} of {}", self.drawn + 1, self.rules.draws)),
+            ],
+            Phase::Result => vec![
+                Line::raw("[Enter] Next"),
+                Line::raw("[Q] Quit"),
+            ],
+            _ => vec![Line::raw("")],
+        };
+        render_help(frame, &lines, area);
+    }
+
+    fn scene(&mut self, frame: &mut Frame, area: Rect) {
+        let chunk = area.height / 2;
+        let bots = self.table.seats.len() as u16 - 1;
+        let width = area.width / bots;
+        let reveal = self.phase == Phase::Result;
+
+        for (i, seat) in self.table.seats.iter().enumerate().skip(1) {
+            let x = area.x + (i as u16 - 1) * width;
+            let label = Rect::new(x + 2, area.y, width.saturating_sub(2), 1);
+            let cards = Rect::new(x, area.y + 1, width, chunk.saturating_sub(1));
+
+            frame.render_widget(Line::raw(self.table.label(i)), label);
+
+            if seat.folded {
+                continue;
+            }
+            if reveal && self.table.live() > 1 {
+                render_hand(frame, &seat.hand, cards, false);
+            } else {
+                render_backs(frame, seat.hand.cards.len(), cards);
+            }
+        }
+
+        let player = &self.table.seats[0];
+        let label = Rect::new(area.x + 2, area.y + chunk, area.width.saturating_sub(2), 1);
+        let cards = Rect::new(area.x, area.y + chunk + 1, area.width, chunk.saturating_sub(1));
+
+        let rank = self.rank(&player.hand);
+        frame.render_widget(Line::raw(format!("{}: {} {}", player.name, rank, player.last)), label);
+
+        if self.phase == Phase::Swap {
+            render_pick(frame, &player.hand, cards, self.cursor, &self.marks);
+        } else {
+            render_hand(frame, &player.hand, cards, false);
+        }
+    }
+}
+
+fn strength(hand: &Hand, low: bool, left: bool) -> f32 {
+    if low {
+        let strength = deuce_seven(&hand.cards).strength();
+        return if left && lowball(hand).len() <= 1 { strength.max(0.45) } else { strength };
+    }
+    let strength = evaluate(hand, &Hand::new()).strength();
+    if left && discards(hand).len() == 1 { strength.max(0.35) } else { strength }
+}
+
+pub fn lowball(hand: &Hand) -> Vec<usize> {
+    let cards = &hand.cards;
+    let mut kept = Vec::new();
+    (0..cards.len())
+        .filter(|&i| {
+            let score = cards[i].value.score();
+            let keep = score <= 8 && !kept.contains(&score);
+            if keep {
+                kept.push(score);
+            }
+            !keep
+        })
+        .collect()
+}
+
+pub fn discards(hand: &Hand) -> Vec<usize> {
+    let cards = &hand.cards;
+    let score = evaluate(hand, &Hand::new());
+
+    let mut counts = [0u8; 16];
+    for card in cards {
+        counts[card.value.score() as usize] += 1;
+    }
+
+    match score.rank {
+        Rank::Pair | Rank::TwoPair | Rank::Three => {
+            return (0..cards.len()).filter(|&i| counts[cards[i].value.score() as usize] == 1).collect();
+        }
+        Rank::High => {}
+        _ => return Vec::new(),
+    }
+
+    for suit in SUITS {
+        let off: Vec<usize> = (0..cards.len()).filter(|&i| cards[i].suit != suit).collect();
+        if off.len() == 1 {
+            return off;
+        }
+    }
+
+    for skip in 0..cards.len() {
+        let mut values: Vec<u8> = (0..cards.len()).filter(|&i| i != skip).map(|i| cards[i].value.score()).collect();
+        values.sort();
+        if values[3] - values[0] <= 4 {
+            return vec![skip];
+        }
+        if values[3] == 14 {
+            values.pop();
+            values.insert(0, 1);
+            if values[3] - values[0] <= 4 {
+                return vec![skip];
+            }
+        }
+    }
+
+    let mut order: Vec<usize> = (0..cards.len()).collect();
+    order.sort_by(|&a, &b| cards[b].value.score().cmp(&cards[a].value.score()));
+
+    let keep = if cards[order[0]].value == Value::Ace { 1 } else { 2 };
+    let mut picks = order.split_off(keep);
+    picks.sort();
+    picks
+}
+
+impl Play for Draw {
+    fn handle(&mut self, key: KeyEvent) {
+        match self.phase {
+            Phase::Ante => self.handle_ante(key),
+            Phase::Bet => self.handle_action(key),
+            Phase::Swap => self.handle_swap(key),
+            Phase::Result => self.handle_result(key),
+            _ => {}
+        }
+    }
+
+    fn render(&mut self, frame: &mut Frame, area: Rect) {
+        let inner = area.inner(Margin::new(1, 1));
+
+        let top = Rect::new(inner.x, inner.y, inner.width, 4);
+        let bottom = Rect::new(inner.x, inner.y + inner.height.saturating_sub(5), inner.width, 5);
+        let middle = Rect::new(inner.x, inner.y + 4, inner.width, inner.height.saturating_sub(9));
+
+        self.status(frame, top);
+        self.help(frame, bottom);
+
+        if self.phase != Phase::Ante {
+            self.scene(frame, middle);
+        }
+
+        render_pop(frame, &self.message, inner);
+    }
+
+    fn active(&self) -> bool {
+        self.phase != Phase::End
+    }
+
+    fn chips(&mut self) -> Option<&mut Wallet> {
+        Some(&mut self.table.seats[0].money)
+    }
+
+    fn prime(&mut self, cards: Vec<Card>) -> Result<(), String> {
+        self.shoe.prime(cards)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::*;
+
+    #[test]
+    fn three_full_draws() {
+        let mut draw = Draw::new(TRIPLE);
+        draw.shoe.shuffle();
+        for _ in 0..5 {
+            for seat in &mut draw.table.seats {
+                seat.hand.add(draw.shoe.draw().unwrap());
+            }
+        }
+
+        for _ in 0..3 {
+            for index in 0..draw.table.seats.len() {
+                draw.replace(index, &[0, 1, 2, 3, 4]);
+            }
+        }
+
+        let held: HashSet<Card> = draw.table.seats.iter().flat_map(|seat| seat.hand.cards.clone()).collect();
+        assert_eq!(held.len(), 20);
+        assert_eq!(draw.shoe.remaining() + draw.muck.len(), 32);
+    }
+}
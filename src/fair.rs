@@ -0,0 +1,120 @@
+use rand::RngExt;
+use sha2::{Digest, Sha256};
+
+use crate::card::{Card, Hand, Shoe};
+
+pub struct Fair {
+    pub server: String,
+    pub order: Vec<Card>,
+    pub commitment: String,
+    pub client: Option<String>,
+    pub primed: Vec<Card>,
+}
+
+impl Fair {
+    pub fn new(mut cards: Vec<Card>) -> Self {
+        let mut rng = rand::rng();
+        let server: String = (0..32).map(|_| format!("{:02x}", rng.random::<u8>())).collect();
+        mix(&mut cards, &server);
+        let commitment = commit(&cards, &server);
+        Self { server, order: cards, commitment, client: None, primed: Vec::new() }
+    }
+
+    pub fn deal(&mut self, client: &str) -> Shoe {
+        self.client = Some(client.to_string());
+        Shoe { cards: dealt(&self.order, &self.server, client), dealt: Vec::new(), primed: Vec::new() }
+    }
+
+    pub fn reveal(&self) -> Option<String> {
+        let client = self.client.as_ref()?;
+        let order: String = self.order.iter().map(|card| format!("{:#}", card)).collect();
+        let mut text = format!("commitment {}\nserver {}\nclient {}\norder {}\n", self.commitment, self.server, client, order);
+        if !self.primed.is_empty() {
+            let primed: String = self.primed.iter().map(|card| format!("{:#}", card)).collect();
+            text.push_str(&format!("# scenario cards dealt ahead of the committed order\nprimed {}\n", primed));
+        }
+        Some(text)
+    }
+
+    pub fn save(&self) -> Option<String> {
+        let text = self.reveal()?;
+        let path = format!("fair-{}.txt", &self.commitment[..8]);
+        std::fs::write(&path, text).ok()?;
+        Some(path)
+    }
+}
+
+fn digest(text: &str) -> [u8; 32] {
+    Sha256::digest(text.as_bytes()).into()
+}
+
+pub fn commit(order: &[Card], server: &str) -> String {
+    let order: String = order.iter().map(|card| format!("{:#}", card)).collect();
+    digest(&format!("{}:{}", order, server)).iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+pub fn mix(cards: &mut [Card], seed: &str) {
+    for i in (1..cards.len()).rev() {
+        let hash = digest(&format!("{}:{}", seed, i));
+        let draw = u64::from_be_bytes(hash[..8].try_into().unwrap());
+        cards.swap(i, (draw % (i as u64 + 1)) as usize);
+    }
+}
+
+pub fn dealt(order: &[Card], server: &str, client: &str) -> Vec<Card> {
+    let mut cards = order.to_vec();
+    mix(&mut cards, &format!("{}:{}", server, client));
+    cards
+}
+
+pub fn verify(text: &str) -> Result<(Vec<Card>, usize), String> {
+    let field = |key: &str| {
+        text.lines()
+            .find_map(|line| line.strip_prefix(key).and_then(|rest| rest.strip_prefix(' ').or(Some(rest))))
+            .ok_or_else(|| format!("missing {}", key))
+    };
+
+    let commitment = field("commitment")?;
+    let server = field("server")?;
+    let client = field("client")?;
+    let order: Hand = field("order")?.parse().map_err(|error| format!("{}", error))?;
+
+    if commit(&order.cards, server) != commitment.trim() {
+        return Err("commitment does not match the revealed order and server seed".into());
+    }
+
+    let mut cards = dealt(&order.cards, server, client);
+    cards.reverse();
+
+    let primed: Hand = match field("primed") {
+        Ok(text) => text.parse().map_err(|error| format!("{}", error))?,
+        Err(_) => Hand::new(),
+    };
+    for card in &primed.cards {
+        let index = cards.iter().rposition(|item| item == card).ok_or_else(|| format!("primed {:#} is not in the order", card))?;
+        cards.remove(index);
+    }
+    let count = primed.cards.len();
+    Ok(([primed.cards, cards].concat(), count))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn verify_replays_a_primed_deal() {
+        let mut fair = Fair::new(Shoe::new(1).cards);
+        let mut shoe = fair.deal("client");
+        let primed: Hand = "Th As Th".parse().unwrap();
+        assert!(shoe.prime(primed.cards.clone()).is_err());
+        shoe.prime(primed.cards[..2].to_vec()).unwrap();
+        fair.primed = primed.cards[..2].to_vec();
+
+        let mut cards = Vec::new();
+        while let Some(card) = shoe.draw() {
+            cards.push(card);
+        }
+        assert_eq!(verify(&fair.reveal().unwrap()), Ok((cards, 2)));
+    }
+}
@@ -0,0 +1,434 @@
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::{
+    layout::{Margin, Rect},
+    text::Line,
+    Frame,
+};
+
+use crate::{
+    card::{Card, Hand, Shoe, Suit, SUITS},
+    play::Play,
+    poker::subsets,
+    show::{render_backs, render_card, render_hand, render_help, render_pick, render_pop, render_slot, render_status},
+};
+
+const GOAL: u32 = 100;
+
+#[derive(PartialEq)]
+pub enum Phase {
+    Draw,
+    Discard,
+    Result,
+    Over,
+    End,
+}
+
+#[derive(Clone, Default)]
+pub struct Arrangement {
+    pub melds: Vec<Vec<Card>>,
+    pub deadwood: Vec<Card>,
+}
+
+impl Arrangement {
+    pub fn points(&self) -> u32 {
+        self.deadwood.iter().map(|card| card.value.pips()).sum()
+    }
+}
+
+pub struct Gin {
+    pub phase: Phase,
+    pub shoe: Shoe,
+    pub hands: [Hand; 2],
+    pub pile: Vec<Card>,
+    pub dealer: usize,
+    pub scores: [u32; 2],
+    pub cursor: usize,
+    pub reveal: bool,
+    pub message: String,
+}
+
+impl Gin {
+    pub fn new() -> Self {
+        let mut game = Self {
+            phase: Phase::Draw,
+            shoe: Shoe::new(1),
+            hands: [Hand::new(), Hand::new()],
+            pile: Vec::new(),
+            dealer: 1,
+            scores: [0; 2],
+            cursor: 0,
+            reveal: false,
+            message: String::new(),
+        };
+        game.deal();
+        game
+    }
+
+    fn deal(&mut self) {
+        self.shoe.restock(Shoe::new(1));
+        self.shoe.shuffle();
+        self.dealer = 1 - self.dealer;
+        self.hands = [Hand::new(), Hand::new()];
+        for _ in 0..10 {
+            for hand in &mut self.hands {
+                hand.add(self.shoe.draw().unwrap());
+            }
+        }
+        sort(&mut self.hands[0]);
+        self.pile = vec![self.shoe.draw().unwrap()];
+        self.cursor = 0;
+        self.reveal = false;
+        self.message.clear();
+        self.phase = Phase::Draw;
+
+        if self.dealer == 0 {
+            self.bot();
+        }
+    }
+
+    fn bot(&mut self) {
+        let hand = &self.hands[1].cards;
+        let before = best_discard(hand).1;
+        let top = *self.pile.last().unwrap();
+        let mut trial = hand.clone();
+        trial.push(top);
+
+        let card = if best_discard(&trial).1 < before { self.pile.pop().unwrap() } else { self.shoe.draw().unwrap() };
+        self.hands[1].add(card);
+
+        let (index, left) = best_discard(&self.hands[1].cards);
+        let thrown = self.hands[1].cards.remove(index);
+        self.pile.push(thrown);
+
+        if left <= 10 {
+            self.knock(1);
+        } else if self.shoe.remaining() <= 2 {
+            self.message = "Stock exhausted, no score".into();
+            self.reveal = true;
+            self.phase = Phase::Result;
+        } else {
+            self.phase = Phase::Draw;
+        }
+    }
+
+    fn knock(&mut self, knocker: usize) {
+        let defender = 1 - knocker;
+        let held = arrange(&self.hands[knocker].cards);
+        let mut other = arrange(&self.hands[defender].cards);
+        let gin = held.deadwood.is_empty();
+
+        if !gin {
+            other.deadwood = layoff(&held.melds, &other.deadwood);
+        }
+
+        let (mine, theirs) = (held.points(), other.points());
+        let names = ["You", "Bot"];
+
+        let (winner, points, how) = if gin {
+            (knocker, 25 + theirs, "gin")
+        } else if mine < theirs {
+            (knocker, theirs - mine, "knock")
+        } else {
+            (defender, 25 + mine - theirs, "undercut")
+        };
+
+        self.scores[winner] += points;
+        self.message = format!("{} {} for {}", names[winner], how, points);
+        self.reveal = true;
+
+        if self.scores[winner] >= GOAL {
+            self.message = format!("{} wins the game {} to {}", names[winner], self.scores[winner], self.scores[1 - winner]);
+            self.phase = Phase::Over;
+        } else {
+            self.phase = Phase::Result;
+        }
+    }
+
+    fn handle_draw(&mut self, key: KeyEvent) {
+        let card = match key.code {
+            KeyCode::Char('q') => {
+                self.phase = Phase::End;
+                return;
+            }
+            KeyCode::Char('s') => self.shoe.draw(),
+            KeyCode::Char('p') => self.pile.pop(),
+            _ => return,
+        };
+        if let Some(card) = card {
+            self.hands[0].add(card);
+            sort(&mut self.hands[0]);
+            self.cursor = self.hands[0].cards.iter().position(|other| *other == card).unwrap();
+            self.phase = Phase::Discard;
+        }
+    }
+
+    fn handle_discard(&mut self, key: KeyEvent) {
+        let last = self.hands[0].cards.len() - 1;
+        match key.code {
+            KeyCode::Char('q') => self.phase = Phase::End,
+            KeyCode::Left | KeyCode::Char('a') => self.cursor = self.cursor.saturating_sub(1),
+            KeyCode::Right | KeyCode::Char('d') => self.cursor = (self.cursor + 1).min(last),
+            KeyCode::Enter | KeyCode::Char(' ') => {
+                self.message.clear();
+                let card = self.hands[0].cards.remove(self.cursor);
+                self.pile.push(card);
+                self.cursor = self.cursor.min(last - 1);
+                if self.shoe.remaining() <= 2 {
+                    self.message = "Stock exhausted, no score".into();
+                    self.reveal = true;
+                    self.phase = Phase::Result;
+                } else {
+                    self.bot();
+                }
+            }
+            KeyCode::Char('k') => {
+                let mut rest = self.hands[0].cards.clone();
+                rest.remove(self.cursor);
+                if arrange(&rest).points() > 10 {
+                    self.message = "Deadwood must be 10 or less".into();
+                    return;
+                }
+                self.message.clear();
+                let card = self.hands[0].cards.remove(self.cursor);
+                self.pile.push(card);
+                self.cursor = 0;
+                self.knock(0);
+            }
+            _ => {}
+        }
+    }
+
+    fn handle_next(&mut self, key: KeyEvent) {
+        match key.code {
+            KeyCode::Char('q') => self.phase = Phase::End,
+            KeyCode::Enter => {
+                if self.phase == Phase::Over {
+                    self.scores = [0; 2];
+                }
+                self.deal();
+            }
+            _ => {}
+        }
+    }
+
+    fn status(&self, frame: &mut Frame, area: Rect) {
+        let held = arrange(&self.hands[0].cards);
+        let melds: Vec<String> = held.melds.iter().map(|meld| format!("[{}]", label(meld))).collect();
+        let lines = [
+            Line::raw(format!("You: {}  Bot: {}", self.scores[0], self.scores[1])),
+            Line::raw(format!("Melds: {}", melds.join(" "))),
+            Line::raw(format!("Deadwood: {}", held.points())),
+        ];
+        render_status(frame, &lines, area);
+    }
+
+    fn help(&self, frame: &mut Frame, area: Rect) {
+        let lines = match self.phase {
+            Phase::Draw => vec![
+                Line::raw("[S] Draw from stock"),
+                Line::raw("[P] Take from pile"),
+                Line::raw("[Q] Quit"),
+            ],
+            Phase::Discard => vec![
+                Line::raw("[←→] Move"),
+                Line::raw("[Enter] Discard"),
+                Line::raw("[K] Knock with this discard"),
+                Line::raw("[Q] Quit"),
+            ],
+            Phase::Result | Phase::Over => vec![
+                Line::raw("[Enter] Next"),
+                Line::raw("[Q] Quit"),
+            ],
+            _ => vec![Line::raw("")],
+        };
+        render_help(frame, &lines, area);
+    }
+
+    fn scene(&mut self, frame: &mut Frame, area: Rect) {
+        let chunk = area.height / 3;
+        let bot = Rect::new(area.x, area.y, area.width, chunk);
+        let table = Rect::new(area.x, area.y + chunk, area.width, chunk);
+        let mine = Rect::new(area.x, area.y + chunk * 2, area.width, chunk);
+
+        if self.reveal {
+            let mut hand = self.hands[1].clone();
+            sort(&mut hand);
+            render_hand(frame, &hand, bot, false);
+        } else {
+            render_backs(frame, self.hands[1].cards.len(), bot);
+        }
+
+        let middle = table.x + table.width / 2;
+        let y = table.y + table.height.saturating_sub(7) / 2;
+        let stock = Rect::new(middle.saturating_sub(12), y, 10, 7.min(table.height));
+        let pile = Rect::new(middle + 2, y, 10, 7.min(table.height));
+
+        if self.shoe.remaining() > 0 {
+            render_backs(frame, 1, stock);
+        } else {
+            render_slot(frame, "", stock);
+        }
+        match self.pile.last() {
+            Some(card) => render_card(frame, card, pile),
+            None => render_slot(frame, "", pile),
+        }
+
+        let held = arrange(&self.hands[0].cards);
+        let marks: Vec<bool> = self.hands[0].cards.iter().map(|card| held.deadwood.contains(card)).collect();
+        let cursor = if self.phase == Phase::Discard { self.cursor } else { usize::MAX };
+        render_pick(frame, &self.hands[0], mine, cursor, &marks);
+    }
+}
+
+pub fn melds(cards: &[Card]) -> Vec<Vec<Card>> {
+    let mut items = Vec::new();
+
+    for order in 1..=13 {
+        let same: Vec<Card> = cards.iter().copied().filter(|card| card.value.order() == order).collect();
+        if same.len() >= 3 {
+            items.extend(subsets(&same, 3));
+        }
+        if same.len() == 4 {
+            items.push(same);
+        }
+    }
+
+    for suit in SUITS {
+        let mut line: Vec<Card> = cards.iter().copied().filter(|card| card.suit == suit).collect();
+        line.sort_by_key(|card| card.value.order());
+        for start in 0..line.len() {
+            let mut end = start + 1;
+            while end < line.len() && line[end].value.order() == line[end - 1].value.order() + 1 {
+                end += 1;
+                if end - start >= 3 {
+                    items.push(line[start..end].to_vec());
+                }
+            }
+        }
+    }
+
+    items
+}
+
+pub fn arrange(cards: &[Card]) -> Arrangement {
+    let options = melds(cards);
+    let mut best = Arrangement { melds: Vec::new(), deadwood: cards.to_vec() };
+    search(&options, 0, &mut Vec::new(), cards, &mut best);
+    best
+}
+
+fn search(options: &[Vec<Card>], from: usize, chosen: &mut Vec<Vec<Card>>, cards: &[Card], best: &mut Arrangement) {
+    let deadwood: Vec<Card> = cards.iter().copied().filter(|card| !chosen.iter().any(|meld| meld.contains(card))).collect();
+    let points: u32 = deadwood.iter().map(|card| card.value.pips()).sum();
+    if points < best.points() {
+        *best = Arrangement { melds: chosen.clone(), deadwood };
+    }
+
+    for index in from..options.len() {
+        let meld = &options[index];
+        if meld.iter().all(|card| !chosen.iter().any(|other| other.contains(card))) {
+            chosen.push(meld.clone());
+            search(options, index + 1, chosen, cards, best);
+            chosen.pop();
+        }
+    }
+}
+
+pub fn layoff(melds: &[Vec<Card>], deadwood: &[Card]) -> Vec<Card> {
+    let mut melds = melds.to_vec();
+    let mut left = deadwood.to_vec();
+
+    loop {
+        let mut placed = None;
+        'scan: for (i, card) in left.iter().enumerate() {
+            for meld in &mut melds {
+                if fits(meld, card) {
+                    meld.push(*card);
+                    placed = Some(i);
+                    break 'scan;
+                }
+            }
+        }
+        match placed {
+            Some(i) => {
+                left.remove(i);
+            }
+            None => return left,
+        }
+    }
+}
+
+fn fits(meld: &[Card], card: &Card) -> bool {
+    if meld.iter().all(|other| other.value == meld[0].value) {
+        return card.value == meld[0].value;
+    }
+    if card.suit != meld[0].suit {
+        return false;
+    }
+    let low = meld.iter().map(|other| other.value.order()).min().unwrap();
+    let high = meld.iter().map(|other| other.value.order()).max().unwrap();
+    card.value.order() + 1 == low || card.value.order() == high + 1
+}
+
+fn best_discard(cards: &[Card]) -> (usize, u32) {
+    (0..cards.len())
+        .map(|i| {
+            let mut rest = cards.to_vec();
+            let thrown = rest.remove(i);
+            (i, arrange(&rest).points(), thrown.value.pips())
+        })
+        .min_by_key(|&(_, points, pips)| (points, std::cmp::Reverse(pips)))
+        .map(|(i, points, _)| (i, points))
+        .unwrap()
+}
+
+fn label(cards: &[Card]) -> String {
+    cards.iter().map(|card| format!("{}{}", card.value, card.suit)).collect::<Vec<String>>().join(" ")
+}
+
+fn sort(hand: &mut Hand) {
+    let held = arrange(&hand.cards);
+    let mut cards: Vec<Card> = held.melds.concat();
+    let mut rest = held.deadwood;
+    rest.sort_by_key(|card| card.value.order());
+    cards.extend(rest);
+    hand.cards = cards;
+}
+
+impl Play for Gin {
+    fn handle(&mut self, key: KeyEvent) {
+        match self.phase {
+            Phase::Draw => self.handle_draw(key),
+            Phase::Discard => self.handle_discard(key),
+            Phase::Result | Phase::Over => self.handle_next(key),
+            _ => {}
+        }
+    }
+
+    fn render(&mut self, frame: &mut Frame, area: Rect) {
+        let inner = area.inner(Margin::new(1, 1));
+
+        let top = Rect::new(inner.x, inner.y, inner.width, 3);
+        let bottom = Rect::new(inner.x, inner.y + inner.height.saturating_sub(4), inner.width, 4);
+        let middle = Rect::new(inner.x, inner.y + 3, inner.width, inner.height.saturating_sub(7));
+
+        self.status(frame, top);
+        self.help(frame, bottom);
+        self.scene(frame, middle);
+
+        render_pop(frame, &self.message, inner);
+    }
+
+    fn active(&self) -> bool {
+        self.phase != Phase::End
+    }
+
+    fn prime(&mut self, cards: Vec<Card>) -> Result<(), String> {
+        let mut shoe = Shoe::new(1);
+        shoe.prime(cards)?;
+        self.shoe = shoe;
+        self.dealer = 1 - self.dealer;
+        self.deal();
+        Ok(())
+    }
+}
@@ -0,0 +1,441 @@
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::{
+    layout::{Margin, Rect},
+    text::Line,
+    Frame,
+};
+
+use crate::{
+    card::{Card, Hand, Shoe, Suit, Value},
+    play::Play,
+    show::{compass, render_backs, render_card, render_help, render_pick, render_pop, render_status},
+    trick::Trick,
+};
+
+const NAMES: [&str; 4] = ["You", "West", "North", "East"];
+const PASSES: [&str; 4] = ["Left", "Right", "Across", "Hold"];
+
+#[derive(PartialEq)]
+pub enum Phase {
+    Pass,
+    Play,
+    Taken,
+    Tally,
+    Over,
+    End,
+}
+
+pub struct Hearts {
+    pub phase: Phase,
+    pub shoe: Shoe,
+    pub hands: Vec<Hand>,
+    pub taken: [u32; 4],
+    pub scores: [u32; 4],
+    pub round: usize,
+    pub trick: Trick,
+    pub broken: bool,
+    pub first: bool,
+    pub cursor: usize,
+    pub marks: Vec<bool>,
+    pub message: String,
+}
+
+impl Hearts {
+    pub fn new() -> Self {
+        let mut game = Self {
+            phase: Phase::Pass,
+            shoe: Shoe::new(1),
+            hands: Vec::new(),
+            taken: [0; 4],
+            scores: [0; 4],
+            round: 0,
+            trick: Trick::new(0, 4),
+            broken: false,
+            first: true,
+            cursor: 0,
+            marks: Vec::new(),
+            message: String::new(),
+        };
+        game.deal();
+        game
+    }
+
+    fn deal(&mut self) {
+        self.shoe.restock(Shoe::new(1));
+        self.shoe.shuffle();
+
+        self.hands = (0..4).map(|_| Hand::new()).collect();
+        for i in 0..52 {
+            self.hands[i % 4].add(self.shoe.draw().unwrap());
+        }
+        for hand in &mut self.hands {
+            sort(hand);
+        }
+
+        self.taken = [0; 4];
+        self.cursor = 0;
+        self.marks = vec![false; 13];
+        self.message.clear();
+
+        if self.round % 4 == 3 {
+            self.start();
+        } else {
+            self.phase = Phase::Pass;
+        }
+    }
+
+    fn target(&self, seat: usize) -> usize {
+        match self.round % 4 {
+            0 => (seat + 1) % 4,
+            1 => (seat + 3) % 4,
+            _ => (seat + 2) % 4,
+        }
+    }
+
+    fn pass(&mut self) {
+        let mut picks = vec![(0..13).filter(|&i| self.marks[i]).collect::<Vec<usize>>()];
+        for seat in 1..4 {
+            picks.push(dumps(&self.hands[seat]));
+        }
+
+        let mut moving = Vec::new();
+        for (seat, pick) in picks.iter().enumerate() {
+            let cards: Vec<Card> = pick.iter().map(|&i| self.hands[seat].cards[i]).collect();
+            moving.push((self.target(seat), cards));
+        }
+        for (seat, pick) in picks.iter().enumerate() {
+            for &i in pick.iter().rev() {
+                self.hands[seat].cards.remove(i);
+            }
+        }
+        for (seat, cards) in moving {
+            self.hands[seat].cards.extend(cards);
+            sort(&mut self.hands[seat]);
+        }
+
+        self.marks = vec![false; 13];
+        self.start();
+    }
+
+    fn start(&mut self) {
+        let club = Card { suit: Suit::Clubs, value: Value::Two };
+        let leader = (0..4).find(|&seat| self.hands[seat].cards.contains(&club)).unwrap();
+        self.trick = Trick::new(leader, 4);
+        self.broken = false;
+        self.first = true;
+        self.phase = Phase::Play;
+        self.flow();
+    }
+
+    fn allowed(&self, seat: usize) -> Vec<usize> {
+        let hand = &self.hands[seat];
+        let legal = self.trick.legal(hand);
+        let points = |i: &usize| worth(&hand.cards[*i]) > 0;
+
+        let narrowed: Vec<usize> = if self.trick.led().is_none() {
+            if self.first {
+                legal.iter().copied().filter(|&i| hand.cards[i] == Card { suit: Suit::Clubs, value: Value::Two }).collect()
+            } else if !self.broken {
+                legal.iter().copied().filter(|&i| hand.cards[i].suit != Suit::Hearts).collect()
+            } else {
+                Vec::new()
+            }
+        } else if self.first {
+            legal.iter().copied().filter(|i| !points(i)).collect()
+        } else {
+            Vec::new()
+        };
+
+        if narrowed.is_empty() { legal } else { narrowed }
+    }
+
+    fn place(&mut self, seat: usize, index: usize) {
+        let card = self.hands[seat].cards.remove(index);
+        if card.suit == Suit::Hearts {
+            self.broken = true;
+        }
+        self.trick.play(card);
+    }
+
+    fn flow(&mut self) {
+        while !self.trick.done() && self.trick.turn() != 0 {
+            let seat = self.trick.turn();
+            let index = self.choose(seat);
+            self.place(seat, index);
+        }
+
+        if self.trick.done() {
+            let winner = self.trick.winner(None);
+            self.message = format!("{} takes the trick", NAMES[winner]);
+            self.phase = Phase::Taken;
+        }
+    }
+
+    fn choose(&self, seat: usize) -> usize {
+        let hand = &self.hands[seat];
+        let legal = self.allowed(seat);
+        let score = |i: &usize| hand.cards[*i].value.score();
+        let lowest = || *legal.iter().min_by_key(|i| score(i)).unwrap();
+        let highest = || *legal.iter().max_by_key(|i| score(i)).unwrap();
+
+        let Some(led) = self.trick.led() else {
+            return lowest();
+        };
+
+        if hand.cards[legal[0]].suit == led {
+            let top = self.trick.best(None).unwrap().1.value.score();
+            let under: Vec<usize> = legal.iter().copied().filter(|i| score(i) < top).collect();
+            if let Some(&best) = under.iter().max_by_key(|i| score(i)) {
+                return best;
+            }
+            let clean = self.trick.cards.iter().all(|(_, card)| worth(card) == 0);
+            return if self.trick.cards.len() == 3 && clean { highest() } else { lowest() };
+        }
+
+        let queen = Card { suit: Suit::Spades, value: Value::Queen };
+        if let Some(&index) = legal.iter().find(|&&i| hand.cards[i] == queen) {
+            return index;
+        }
+        let hearts: Vec<usize> = legal.iter().copied().filter(|&i| hand.cards[i].suit == Suit::Hearts).collect();
+        if let Some(&index) = hearts.iter().max_by_key(|i| score(i)) {
+            return index;
+        }
+        highest()
+    }
+
+    fn collect(&mut self) {
+        let winner = self.trick.winner(None);
+        self.taken[winner] += self.trick.cards.iter().map(|(_, card)| worth(card)).sum::<u32>();
+        self.trick = Trick::new(winner, 4);
+        self.first = false;
+        self.message.clear();
+
+        if self.hands[0].cards.is_empty() {
+            self.tally();
+        } else {
+            self.phase = Phase::Play;
+            self.flow();
+        }
+    }
+
+    fn tally(&mut self) {
+        if let Some(moon) = (0..4).find(|&seat| self.taken[seat] == 26) {
+            for seat in 0..4 {
+                self.taken[seat] = if seat == moon { 0 } else { 26 };
+            }
+            self.message = format!("{} shot the moon", NAMES[moon]);
+        }
+
+        for seat in 0..4 {
+            self.scores[seat] += self.taken[seat];
+        }
+
+        if self.scores.iter().any(|&score| score >= 100) {
+            let winner = (0..4).min_by_key(|&seat| self.scores[seat]).unwrap();
+            self.message = format!("{} wins the game", NAMES[winner]);
+            self.phase = Phase::Over;
+        } else {
+            self.phase = Phase::Tally;
+        }
+    }
+
+    fn handle_pass(&mut self, key: KeyEvent) {
+        match key.code {
+            KeyCode::Char('q') => self.phase = Phase::End,
+            KeyCode::Left | KeyCode::Char('a') => self.cursor = self.cursor.saturating_sub(1),
+            KeyCode::Right | KeyCode::Char('d') => self.cursor = (self.cursor + 1).min(12),
+            KeyCode::Char(' ') => {
+                let count = self.marks.iter().filter(|&&mark| mark).count();
+                if self.marks[self.cursor] || count < 3 {
+                    self.marks[self.cursor] = !self.marks[self.cursor];
+                }
+            }
+            KeyCode::Enter if self.marks.iter().filter(|&&mark| mark).count() == 3 => {
+                self.cursor = 0;
+                self.pass();
+            }
+            _ => {}
+        }
+    }
+
+    fn handle_play(&mut self, key: KeyEvent) {
+        let last = self.hands[0].cards.len().saturating_sub(1);
+        match key.code {
+            KeyCode::Char('q') => self.phase = Phase::End,
+            KeyCode::Left | KeyCode::Char('a') => self.cursor = self.cursor.saturating_sub(1),
+            KeyCode::Right | KeyCode::Char('d') => self.cursor = (self.cursor + 1).min(last),
+            KeyCode::Enter | KeyCode::Char(' ') => {
+                if self.trick.turn() != 0 {
+                    return;
+                }
+                if !self.allowed(0).contains(&self.cursor) {
+                    self.message = "You can't play that card".into();
+                    return;
+                }
+                self.message.clear();
+                self.place(0, self.cursor);
+                self.cursor = self.cursor.min(self.hands[0].cards.len().saturating_sub(1));
+                self.flow();
+            }
+            _ => {}
+        }
+    }
+
+    fn handle_next(&mut self, key: KeyEvent) {
+        match key.code {
+            KeyCode::Char('q') => self.phase = Phase::End,
+            KeyCode::Enter => match self.phase {
+                Phase::Taken => self.collect(),
+                Phase::Tally => {
+                    self.round += 1;
+                    self.deal();
+                }
+                _ => {
+                    self.scores = [0; 4];
+                    self.round = 0;
+                    self.deal();
+                }
+            },
+            _ => {}
+        }
+    }
+
+    fn status(&self, frame: &mut Frame, area: Rect) {
+        let line = |values: &[u32; 4]| {
+            (0..4).map(|seat| format!("{} {}", NAMES[seat], values[seat])).collect::<Vec<String>>().join("  ")
+        };
+        let lines = [
+            Line::raw(format!("Scores: {}", line(&self.scores))),
+            Line::raw(format!("Hand: {}", line(&self.taken))),
+            Line::raw(format!("Pass: {}{}", PASSES[self.round % 4], if self.broken { "  Hearts broken" } else { "" })),
+        ];
+        render_status(frame, &lines, area);
+    }
+
+    fn help(&self, frame: &mut Frame, area: Rect) {
+        let lines = match self.phase {
+            Phase::Pass => vec![
+                Line::raw("[←→] Move"),
+                Line::raw("[Space] Mark three cards"),
+                Line::raw("[Enter] Pass"),
+                Line::raw("[Q] Quit"),
+            ],
+            Phase::Play => vec![
+                Line::raw("[←→] Move"),
+                Line::raw("[Enter] Play"),
+                Line::raw("[Q] Quit"),
+            ],
+            Phase::Taken | Phase::Tally => vec![
+                Line::raw("[Enter] Next"),
+                Line::raw("[Q] Quit"),
+            ],
+            Phase::Over => vec![
+                Line::raw("[Enter] New game"),
+                Line::raw("[Q] Quit"),
+            ],
+            _ => vec![Line::raw("")],
+        };
+        render_help(frame, &lines, area);
+    }
+
+    fn scene(&mut self, frame: &mut Frame, area: Rect) {
+        let (seats, center) = compass(area, 20, 9);
+
+        for seat in 1..4 {
+            let spot = seats[seat];
+            let label = format!("{} ({})", NAMES[seat], self.hands[seat].cards.len());
+            frame.render_widget(Line::raw(label), Rect::new(spot.x + 1, spot.y, spot.width.saturating_sub(1), 1));
+            let backs = Rect::new(spot.x, spot.y + 1, spot.width, spot.height.saturating_sub(1));
+            render_backs(frame, self.hands[seat].cards.len().min(if seat == 2 { 13 } else { 1 }), backs);
+        }
+
+        let hand = seats[0];
+        let marks = if self.phase == Phase::Pass { self.marks.clone() } else { Vec::new() };
+        let cursor = if matches!(self.phase, Phase::Pass | Phase::Play) { self.cursor } else { usize::MAX };
+        render_pick(frame, &self.hands[0], hand, cursor, &marks);
+
+        let width = 10.min(center.width / 3);
+        let height = 7.min(center.height / 2);
+        let middle = center.x + center.width.saturating_sub(width) / 2;
+        let spots = [
+            Rect::new(middle, center.y + center.height.saturating_sub(height), width, height),
+            Rect::new(middle.saturating_sub(width + 2), center.y + center.height.saturating_sub(height) / 2, width, height),
+            Rect::new(middle, center.y, width, height),
+            Rect::new(middle + width + 2, center.y + center.height.saturating_sub(height) / 2, width, height),
+        ];
+        for (seat, card) in &self.trick.cards {
+            render_card(frame, card, spots[*seat]);
+        }
+    }
+}
+
+pub fn worth(card: &Card) -> u32 {
+    match (card.suit, card.value) {
+        (Suit::Hearts, _) => 1,
+        (Suit::Spades, Value::Queen) => 13,
+        _ => 0,
+    }
+}
+
+fn dumps(hand: &Hand) -> Vec<usize> {
+    let danger = |card: &Card| {
+        let bonus = match (card.suit, card.value) {
+            (Suit::Spades, Value::Queen | Value::King | Value::Ace) => 20,
+            (Suit::Hearts, _) => 2,
+            _ => 0,
+        };
+        card.value.score() as u32 + bonus
+    };
+    let mut order: Vec<usize> = (0..hand.cards.len()).collect();
+    order.sort_by_key(|&i| std::cmp::Reverse(danger(&hand.cards[i])));
+    let mut picks: Vec<usize> = order.into_iter().take(3).collect();
+    picks.sort();
+    picks
+}
+
+fn sort(hand: &mut Hand) {
+    let order = |suit: Suit| match suit {
+        Suit::Clubs => 0,
+        Suit::Diamonds => 1,
+        Suit::Spades => 2,
+        Suit::Hearts => 3,
+    };
+    hand.cards.sort_by_key(|card| (order(card.suit), card.value.score()));
+}
+
+impl Play for Hearts {
+    fn handle(&mut self, key: KeyEvent) {
+        match self.phase {
+            Phase::Pass => self.handle_pass(key),
+            Phase::Play => self.handle_play(key),
+            Phase::End => {}
+            _ => self.handle_next(key),
+        }
+    }
+
+    fn render(&mut self, frame: &mut Frame, area: Rect) {
+        let inner = area.inner(Margin::new(1, 1));
+
+        let top = Rect::new(inner.x, inner.y, inner.width, 3);
+        let bottom = Rect::new(inner.x, inner.y + inner.height.saturating_sub(4), inner.width, 4);
+        let middle = Rect::new(inner.x, inner.y + 3, inner.width, inner.height.saturating_sub(7));
+
+        self.status(frame, top);
+        self.help(frame, bottom);
+        self.scene(frame, middle);
+
+        render_pop(frame, &self.message, inner);
+    }
+
+    fn active(&self) -> bool {
+        self.phase != Phase::End
+    }
+
+    fn prime(&mut self, cards: Vec<Card>) -> Result<(), String> {
+        let mut shoe = Shoe::new(1);
+        shoe.prime(cards)?;
+        self.shoe = shoe;
+        self.deal();
+        Ok(())
+    }
+}
@@ -1,17 +1,16 @@
 use crossterm::event::{KeyCode, KeyEvent};
 use ratatui::{
-    layout::{Alignment, Margin, Rect},
-    style::{Color, Style},
+    layout::{Margin, Rect},
     text::Line,
-    widgets::{Clear, Paragraph},
     Frame,
 };
 
 use crate::{
-    card::{Hand, Shoe},
+    card::{Card, Hand, Shoe},
+    chips::{net, Chips, Error, Wallet, HOUSE, STEP},
     play::Play,
     poker::evaluate,
-    show::render_hand,
+    show::{render_hand, render_help, render_pop, render_status},
 };
 
 #[derive(PartialEq)]
@@ -31,9 +30,10 @@ pub struct Holdem {
     pub player: Hand,
     pub dealer: Hand,
     pub board: Hand,
-    pub money: i32,
-    pub bet: i32,
-    pub pot: i32,
+    pub money: Wallet,
+    pub bank: Wallet,
+    pub bet: Chips,
+    pub pot: Wallet,
     pub message: String,
 }
 
@@ -47,23 +47,23 @@ impl Holdem {
             player: Hand::new(),
             dealer: Hand::new(),
             board: Hand::new(),
-            money: 1000,
-            bet: 10,
-            pot: 0,
+            money: Wallet::new(Chips::ZERO),
+            bank: Wallet::new(HOUSE),
+            bet: STEP,
+            pot: Wallet::new(Chips::ZERO),
             message: String::new(),
         }
     }
 
-    fn deal(&mut self) {
+    fn deal(&mut self) -> Result<(), Error> {
+        self.wager()?;
         self.player.cards.clear();
         self.dealer.cards.clear();
         self.board.cards.clear();
         self.message.clear();
-        self.pot = self.bet * 2;
-        self.money -= self.bet;
 
         if self.shoe.remaining() < 15 {
-            self.shoe = Shoe::new(1);
+            self.shoe.restock(Shoe::new(1));
             self.shoe.shuffle();
         }
 
@@ -71,61 +71,74 @@ impl Holdem {
             self.player.add(self.shoe.draw().unwrap());
             self.dealer.add(self.shoe.draw().unwrap());
         }
+        Ok(())
     }
 
-    fn resolve(&mut self) {
+    fn wager(&mut self) -> Result<(), Error> {
+        self.money.transfer(&mut self.pot, self.bet)?;
+        self.bank.transfer(&mut self.pot, self.bet)
+    }
+
+    fn collect(&mut self) -> Result<(), Error> {
+        let rest = self.pot.balance();
+        self.pot.transfer(&mut self.bank, rest)
+    }
+
+    fn resolve(&mut self) -> Result<(), Error> {
         let player = evaluate(&self.player, &self.board);
         let dealer = evaluate(&self.dealer, &self.board);
+        let (staked, _) = self.pot.balance().split(2);
 
-        if player > dealer {
-            self.message = "Win".into();
-            self.money += self.pot;
+        let (text, paid) = if player > dealer {
+            ("Win", self.pot.balance())
         } else if dealer > player {
-            self.message = "Lose".into();
+            ("Lose", Chips::ZERO)
         } else {
-            self.message = "Push".into();
-            self.money += self.pot / 2;
-        }
-        self.pot = 0;
+            ("Push", staked)
+        };
+        self.pot.transfer(&mut self.money, paid)?;
+        self.collect()?;
+        self.message = format!("{} ({})", text, net(paid, staked));
 
-        if self.money <= 0 {
+        if self.money.broke() {
             self.message.push_str(" Bankrupt");
         }
+        Ok(())
     }
 
     fn handle_ante(&mut self, key: KeyEvent) {
         match key.code {
             KeyCode::Char('q') => self.phase = Phase::End,
             KeyCode::Up | KeyCode::Char('w') => {
-                self.bet = (self.bet + 10).min(self.money);
+                self.bet = self.bet.raise(STEP, self.money.balance());
             }
             KeyCode::Down | KeyCode::Char('s') => {
-                self.bet = (self.bet - 10).max(10);
-            }
-            KeyCode::Enter => {
-                if self.bet > self.money {
-                    return;
-                }
-                self.deal();
-                self.phase = Phase::Preflop;
+                self.bet = self.bet.lower(STEP, STEP);
             }
+            KeyCode::Enter => match self.deal() {
+                Ok(()) => self.phase = Phase::Preflop,
+                Err(error) => self.message = error.to_string(),
+            },
             _ => {}
         }
     }
 
     fn handle_action(&mut self, key: KeyEvent) {
         match key.code {
-            KeyCode::Char('q') => self.phase = Phase::End,
             KeyCode::Char('f') => {
-                self.message = "Fold".into();
-                self.pot = 0;
+                self.message = format!("Fold ({})", net(Chips::ZERO, self.pot.balance().split(2).0));
+                if let Err(error) = self.collect() {
+                    self.message = error.to_string();
+                }
+                if self.money.broke() {
+                    self.message.push_str(" Bankrupt");
+                }
                 self.phase = Phase::Result;
             }
-            KeyCode::Char('c') => {
-                self.money -= self.bet;
-                self.pot += self.bet * 2;
-                self.advance();
-            }
+            KeyCode::Char('c') => match self.wager() {
+                Ok(()) => self.advance(),
+                Err(error) => self.message = error.to_string(),
+            },
             _ => {}
         }
     }
@@ -134,10 +147,10 @@ impl Holdem {
         match key.code {
             KeyCode::Char('q') => self.phase = Phase::End,
             KeyCode::Enter => {
-                if self.money <= 0 {
+                if self.money.broke() {
                     self.phase = Phase::End;
                 } else {
-                    self.bet = 10.min(self.money);
+                    self.bet = STEP.min(self.money.balance());
                     self.message.clear();
                     self.phase = Phase::Ante;
                 }
@@ -163,7 +176,9 @@ impl Holdem {
                 self.phase = Phase::River;
             }
             Phase::River => {
-                self.resolve();
+                if let Err(error) = self.resolve() {
+                    self.message = error.to_string();
+                }
                 self.phase = Phase::Result;
             }
             _ => {}
@@ -171,14 +186,12 @@ impl Holdem {
     }
 
     fn status(&self, frame: &mut Frame, area: Rect) {
-        let money = Line::raw(format!("Money: ${}", self.money));
-        let bet = Line::raw(format!("Bet: ${}", self.bet));
-        let pot = Line::raw(format!("Pot: ${}", self.pot));
-        let width = area.width.saturating_sub(2);
-        frame.render_widget(Clear, area);
-        frame.render_widget(money, Rect::new(area.x + 2, area.y, width, 1));
-        frame.render_widget(bet, Rect::new(area.x + 2, area.y + 1, width, 1));
-        frame.render_widget(pot, Rect::new(area.x + 2, area.y + 2, width, 1));
+        let lines = [
+            Line::raw(format!("Money: {}", self.money.balance())),
+            Line::raw(format!("Bet: {}", self.bet)),
+            Line::raw(format!("Pot: {}", self.pot.balance())),
+        ];
+        render_status(frame, &lines, area);
     }
 
     fn help(&self, frame: &mut Frame, area: Rect) {
@@ -191,7 +204,6 @@ impl Holdem {
             Phase::Preflop | Phase::Flop | Phase::Turn | Phase::River => vec![
                 Line::raw("[C] Call"),
                 Line::raw("[F] Fold"),
-                Line::raw("[Q] Quit"),
             ],
             Phase::Result => vec![
                 Line::raw("[Enter] Next"),
@@ -200,29 +212,7 @@ impl Holdem {
             _ => vec![Line::raw("")],
         };
 
-        let y = area.y + area.height.saturating_sub(lines.len() as u16);
-        let width = area.width.saturating_sub(4);
-        frame.render_widget(Clear, area);
-        for (i, line) in lines.iter().enumerate() {
-            let item = Rect::new(area.x + 2, y + i as u16, width, 1);
-            frame.render_widget(line.clone(), item);
-        }
-    }
-
-    fn pop(&self, frame: &mut Frame, area: Rect) {
-        if self.message.is_empty() {
-            return;
-        }
-        let width = self.message.len() as u16 + 6;
-        let x = area.x + area.width.saturating_sub(width) / 2;
-        let y = area.y + area.height / 2;
-        let spot = Rect::new(x, y, width, 1);
-
-        let text = Line::raw(&self.message).style(Style::default().fg(Color::Black).bg(Color::White));
-        let item = Paragraph::new(text).alignment(Alignment::Center);
-
-        frame.render_widget(Clear, spot);
-        frame.render_widget(item, spot);
+        render_help(frame, &lines, area);
     }
 
     fn scene(&mut self, frame: &mut Frame, area: Rect) {
@@ -267,10 +257,18 @@ impl Play for Holdem {
             _ => {}
         }
 
-        self.pop(frame, inner);
+        render_pop(frame, &self.message, inner);
     }
 
     fn active(&self) -> bool {
         self.phase != Phase::End
     }
+
+    fn chips(&mut self) -> Option<&mut Wallet> {
+        Some(&mut self.money)
+    }
+
+    fn prime(&mut self, cards: Vec<Card>) -> Result<(), String> {
+        self.shoe.prime(cards)
+    }
 }
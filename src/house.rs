@@ -0,0 +1,416 @@
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::{
+    layout::{Margin, Rect},
+    text::Line,
+    Frame,
+};
+
+use crate::{
+    card::{Card, Hand, Shoe},
+    chips::{net, Chips, Error, Wallet, HOUSE, STEP},
+    play::Play,
+    poker::{rate, three, Rank, Score},
+    show::{render_help, render_mask, render_pop, render_status},
+};
+
+#[derive(PartialEq)]
+pub enum Phase {
+    Ante,
+    Decide,
+    Result,
+    End,
+}
+
+pub trait Banker {
+    fn name(&self) -> &'static str;
+    fn size(&self) -> usize;
+    fn rate(&self, cards: &[Card]) -> Score;
+    fn qualifies(&self, score: Score) -> bool;
+    fn call(&self) -> &'static str;
+    fn raise(&self) -> u64;
+    fn pays(&self, score: Score) -> u64;
+    fn upcards(&self) -> usize {
+        0
+    }
+    fn order(&self, score: Score) -> (u8, u32) {
+        (score.rank as u8, score.power)
+    }
+    fn bonus(&self, _score: Score) -> u64 {
+        0
+    }
+    fn side(&self) -> Option<&'static str> {
+        None
+    }
+    fn side_pays(&self, _score: Score) -> u64 {
+        0
+    }
+}
+
+pub struct Caribbean;
+
+impl Banker for Caribbean {
+    fn name(&self) -> &'static str {
+        "Caribbean Stud"
+    }
+
+    fn size(&self) -> usize {
+        5
+    }
+
+    fn rate(&self, cards: &[Card]) -> Score {
+        rate(cards)
+    }
+
+    fn qualifies(&self, score: Score) -> bool {
+        score.rank > Rank::High || (score.top() == 14 && (score.power >> 12) & 0xF == 13)
+    }
+
+    fn call(&self) -> &'static str {
+        "Raise"
+    }
+
+    fn raise(&self) -> u64 {
+        2
+    }
+
+    fn pays(&self, score: Score) -> u64 {
+        match score.rank {
+            Rank::High | Rank::Pair => 1,
+            Rank::TwoPair => 2,
+            Rank::Three => 3,
+            Rank::Straight => 4,
+            Rank::Flush => 5,
+            Rank::FullHouse => 7,
+            Rank::Four => 20,
+            Rank::StraightFlush if score.power == 14 => 100,
+            Rank::StraightFlush => 50,
+            Rank::Five => 100,
+        }
+    }
+
+    fn upcards(&self) -> usize {
+        1
+    }
+}
+
+pub struct ThreeCard;
+
+impl Banker for ThreeCard {
+    fn name(&self) -> &'static str {
+        "Three Card Poker"
+    }
+
+    fn size(&self) -> usize {
+        3
+    }
+
+    fn rate(&self, cards: &[Card]) -> Score {
+        three(cards)
+    }
+
+    fn qualifies(&self, score: Score) -> bool {
+        score.rank > Rank::High || score.top() >= 12
+    }
+
+    fn call(&self) -> &'static str {
+        "Play"
+    }
+
+    fn raise(&self) -> u64 {
+        1
+    }
+
+    fn pays(&self, _score: Score) -> u64 {
+        1
+    }
+
+    fn order(&self, score: Score) -> (u8, u32) {
+        let tier = match score.rank {
+            Rank::High => 0,
+            Rank::Pair => 1,
+            Rank::Flush => 2,
+            Rank::Straight => 3,
+            Rank::Three => 4,
+            _ => 5,
+        };
+        (tier, score.power)
+    }
+
+    fn bonus(&self, score: Score) -> u64 {
+        match score.rank {
+            Rank::Straight => 1,
+            Rank::Three => 4,
+            Rank::StraightFlush => 5,
+            _ => 0,
+        }
+    }
+
+    fn side(&self) -> Option<&'static str> {
+        Some("Pair Plus")
+    }
+
+    fn side_pays(&self, score: Score) -> u64 {
+        match score.rank {
+            Rank::Pair => 1,
+            Rank::Flush => 4,
+            Rank::Straight => 6,
+            Rank::Three => 30,
+            Rank::StraightFlush => 40,
+            _ => 0,
+        }
+    }
+}
+
+pub struct House {
+    pub phase: Phase,
+    pub rules: Box<dyn Banker>,
+    pub shoe: Shoe,
+    pub player: Hand,
+    pub dealer: Hand,
+    pub money: Wallet,
+    pub bank: Wallet,
+    pub bet: Chips,
+    pub side: Chips,
+    pub staked: Chips,
+    pub message: String,
+}
+
+impl House {
+    pub fn new(rules: Box<dyn Banker>) -> Self {
+        Self {
+            phase: Phase::Ante,
+            rules,
+            shoe: Shoe::new(1),
+            player: Hand::new(),
+            dealer: Hand::new(),
+            money: Wallet::new(Chips::ZERO),
+            bank: Wallet::new(HOUSE),
+            bet: STEP,
+            side: Chips::ZERO,
+            staked: Chips::ZERO,
+            message: String::new(),
+        }
+    }
+
+    fn stake(&self) -> Result<Chips, Error> {
+        self.bet.times(1 + self.rules.raise())?.plus(self.side)
+    }
+
+    fn deal(&mut self) -> Result<(), Error> {
+        let stake = self.stake()?;
+        if !self.money.covers(stake) {
+            return Err(Error::Short { need: stake, have: self.money.balance() });
+        }
+        self.money.transfer(&mut self.bank, stake)?;
+        self.staked = stake;
+        self.shoe.restock(Shoe::new(1));
+        self.shoe.shuffle();
+        self.player = Hand::new();
+        self.dealer = Hand::new();
+        self.message.clear();
+
+        for _ in 0..self.rules.size() {
+            self.player.add(self.shoe.draw().unwrap());
+            self.dealer.add(self.shoe.draw().unwrap());
+        }
+        Ok(())
+    }
+
+    fn resolve(&mut self, play: bool) -> Result<(), Error> {
+        let mine = self.rules.rate(&self.player.cards);
+        let theirs = self.rules.rate(&self.dealer.cards);
+        let mut paid = Chips::ZERO;
+
+        if !play {
+            self.message = "Fold".into();
+        } else {
+            let raise = self.bet.times(self.rules.raise())?;
+            self.money.transfer(&mut self.bank, raise)?;
+            self.staked = self.staked.plus(raise)?;
+            if !self.rules.qualifies(theirs) {
+                paid = self.bet.times(2)?.plus(raise)?;
+                self.message = "Dealer does not qualify".into();
+            } else {
+                match self.rules.order(mine).cmp(&self.rules.order(theirs)) {
+                    std::cmp::Ordering::Greater => {
+                        paid = self.bet.times(2)?.plus(raise.times(self.rules.pays(mine) + 1)?)?;
+                        self.message = format!("Win with {}", mine.rank);
+                    }
+                    std::cmp::Ordering::Less => self.message = format!("Dealer wins with {}", theirs.rank),
+                    std::cmp::Ordering::Equal => {
+                        paid = self.bet.plus(raise)?;
+                        self.message = "Push".into();
+                    }
+                }
+            }
+            paid = paid.plus(self.bet.times(self.rules.bonus(mine))?)?;
+        }
+
+        match self.rules.side_pays(mine) {
+            0 => {}
+            pays => paid = paid.plus(self.side.times(pays + 1)?)?,
+        }
+
+        self.bank.transfer(&mut self.money, paid)?;
+        self.message = format!("{} ({})", self.message, net(paid, self.staked));
+
+        if self.money.broke() {
+            self.message.push_str(" Bankrupt");
+        }
+        self.phase = Phase::Result;
+        Ok(())
+    }
+
+    fn handle_ante(&mut self, key: KeyEvent) {
+        match key.code {
+            KeyCode::Char('q') => self.phase = Phase::End,
+            KeyCode::Up | KeyCode::Char('w') => {
+                self.bet = self.bet.raise(STEP, self.money.balance());
+            }
+            KeyCode::Down | KeyCode::Char('s') => {
+                self.bet = self.bet.lower(STEP, STEP);
+            }
+            KeyCode::Right | KeyCode::Char('d') if self.rules.side().is_some() => {
+                self.side = self.side.raise(STEP, self.money.balance());
+            }
+            KeyCode::Left | KeyCode::Char('a') => {
+                self.side = self.side.lower(STEP, Chips::ZERO);
+            }
+            KeyCode::Enter => match self.deal() {
+                Ok(()) => self.phase = Phase::Decide,
+                Err(Error::Short { need, .. }) => {
+                    self.message = format!("Need {} to cover the {}", need, self.rules.call().to_lowercase());
+                }
+                Err(error) => self.message = error.to_string(),
+            },
+            _ => {}
+        }
+    }
+
+    fn handle_decide(&mut self, key: KeyEvent) {
+        match key.code {
+            KeyCode::Char('r') | KeyCode::Char('p') => {
+                if let Err(error) = self.resolve(true) {
+                    self.message = error.to_string();
+                }
+            }
+            KeyCode::Char('f') => {
+                if let Err(error) = self.resolve(false) {
+                    self.message = error.to_string();
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn handle_result(&mut self, key: KeyEvent) {
+        match key.code {
+            KeyCode::Char('q') => self.phase = Phase::End,
+            KeyCode::Enter => {
+                if self.money.broke() {
+                    self.phase = Phase::End;
+                } else {
+                    self.bet = self.bet.min(self.money.balance());
+                    self.side = self.side.min(self.money.balance());
+                    self.message.clear();
+                    self.phase = Phase::Ante;
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn status(&self, frame: &mut Frame, area: Rect) {
+        let mut lines = vec![
+            Line::raw(format!("{}  Money: {}", self.rules.name(), self.money.balance())),
+            Line::raw(format!("Ante: {}  {}: {}", self.bet, self.rules.call(), self.bet.times(self.rules.raise()).unwrap_or(self.bet))),
+        ];
+        if let Some(side) = self.rules.side() {
+            lines.push(Line::raw(format!("{}: {}", side, self.side)));
+        }
+        render_status(frame, &lines, area);
+    }
+
+    fn help(&self, frame: &mut Frame, area: Rect) {
+        let lines = match self.phase {
+            Phase::Ante => {
+                let mut lines = vec![Line::raw("[↑↓] Ante")];
+                if let Some(side) = self.rules.side() {
+                    lines.push(Line::raw(format!("[←→] {}", side)));
+                }
+                lines.push(Line::raw("[Enter] Deal"));
+                lines.push(Line::raw("[Q] Quit"));
+                lines
+            }
+            Phase::Decide => vec![
+                Line::raw(format!("[{}] {}", &self.rules.call()[..1], self.rules.call())),
+                Line::raw("[F] Fold"),
+            ],
+            Phase::Result => vec![
+                Line::raw("[Enter] Next"),
+                Line::raw("[Q] Quit"),
+            ],
+            _ => vec![Line::raw("")],
+        };
+        render_help(frame, &lines, area);
+    }
+
+    fn scene(&mut self, frame: &mut Frame, area: Rect) {
+        let chunk = area.height / 2;
+        let dealer = Rect::new(area.x, area.y, area.width, chunk);
+        let player = Rect::new(area.x, area.y + chunk, area.width, chunk);
+
+        let reveal = self.phase == Phase::Result;
+        let hidden: Vec<bool> = (0..self.rules.size()).map(|i| !reveal && i >= self.rules.upcards()).collect();
+        render_mask(frame, &self.dealer, dealer, &hidden);
+        render_mask(frame, &self.player, player, &[]);
+
+        let mine = self.rules.rate(&self.player.cards);
+        frame.render_widget(Line::raw(format!("You: {}", mine.rank)), Rect::new(player.x + 2, player.y, player.width.saturating_sub(2), 1));
+        if reveal {
+            let theirs = self.rules.rate(&self.dealer.cards);
+            let label = format!("Dealer: {}", theirs.rank);
+            frame.render_widget(Line::raw(label), Rect::new(dealer.x + 2, dealer.y + dealer.height.saturating_sub(1), dealer.width.saturating_sub(2), 1));
+        }
+    }
+}
+
+impl Play for House {
+    fn handle(&mut self, key: KeyEvent) {
+        match self.phase {
+            Phase::Ante => self.handle_ante(key),
+            Phase::Decide => self.handle_decide(key),
+            Phase::Result => self.handle_result(key),
+            _ => {}
+        }
+    }
+
+    fn render(&mut self, frame: &mut Frame, area: Rect) {
+        let inner = area.inner(Margin::new(1, 1));
+
+        let top = Rect::new(inner.x, inner.y, inner.width, 3);
+        let bottom = Rect::new(inner.x, inner.y + inner.height.saturating_sub(4), inner.width, 4);
+        let middle = Rect::new(inner.x, inner.y + 3, inner.width, inner.height.saturating_sub(7));
+
+        self.status(frame, top);
+        self.help(frame, bottom);
+
+        if self.phase != Phase::Ante {
+            self.scene(frame, middle);
+        }
+
+        render_pop(frame, &self.message, inner);
+    }
+
+    fn active(&self) -> bool {
+        self.phase != Phase::End
+    }
+
+    fn chips(&mut self) -> Option<&mut Wallet> {
+        Some(&mut self.money)
+    }
+
+    fn prime(&mut self, cards: Vec<Card>) -> Result<(), String> {
+        self.shoe.prime(cards)
+    }
+}
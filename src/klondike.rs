@@ -0,0 +1,460 @@
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::{
+    layout::{Margin, Rect},
+    style::Color,
+    text::Line,
+    Frame,
+};
+
+use crate::{
+    card::{Card, Shoe, Value},
+    play::Play,
+    show::{
+        render_card, render_focus, render_help, render_hidden, render_pop, render_slot, render_stack, render_status,
+    },
+};
+
+#[derive(PartialEq)]
+pub enum Phase {
+    Mode,
+    Play,
+    Won,
+    End,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum Spot {
+    Stock,
+    Waste,
+    Foundation(usize),
+    Column(usize),
+}
+
+#[derive(Clone)]
+pub struct Board {
+    pub stock: Vec<Card>,
+    pub waste: Vec<Card>,
+    pub foundations: [Vec<Card>; 4],
+    pub columns: [Vec<Card>; 7],
+    pub hidden: [usize; 7],
+}
+
+pub struct Klondike {
+    pub phase: Phase,
+    pub shoe: Shoe,
+    pub board: Board,
+    pub history: Vec<Board>,
+    pub draw: usize,
+    pub row: usize,
+    pub col: usize,
+    pub held: Option<Spot>,
+    pub moves: usize,
+    pub message: String,
+}
+
+impl Board {
+    pub fn new(shoe: &mut Shoe) -> Self {
+        shoe.restock(Shoe::new(1));
+        shoe.shuffle();
+
+        let mut columns: [Vec<Card>; 7] = Default::default();
+        let mut hidden = [0; 7];
+        for (i, column) in columns.iter_mut().enumerate() {
+            for _ in 0..=i {
+                column.push(shoe.draw().unwrap());
+            }
+            hidden[i] = i;
+        }
+        let mut stock: Vec<Card> = (0..shoe.remaining()).filter_map(|_| shoe.draw()).collect();
+        stock.reverse();
+
+        Self {
+            stock,
+            waste: Vec::new(),
+            foundations: Default::default(),
+            columns,
+            hidden,
+        }
+    }
+
+    pub fn top(&self, spot: Spot) -> Option<&Card> {
+        match spot {
+            Spot::Stock => None,
+            Spot::Waste => self.waste.last(),
+            Spot::Foundation(i) => self.foundations[i].last(),
+            Spot::Column(i) => self.columns[i].last(),
+        }
+    }
+
+    pub fn turn(&mut self, count: usize) -> bool {
+        if self.stock.is_empty() {
+            if self.waste.is_empty() {
+                return false;
+            }
+            while let Some(card) = self.waste.pop() {
+                self.stock.push(card);
+            }
+            return true;
+        }
+        for _ in 0..count {
+            if let Some(card) = self.stock.pop() {
+                self.waste.push(card);
+            }
+        }
+        true
+    }
+
+    fn fits(&self, card: &Card, to: Spot) -> bool {
+        match to {
+            Spot::Foundation(i) => match self.foundations[i].last() {
+                None => card.value == Value::Ace,
+                Some(top) => top.suit == card.suit && top.value.order() + 1 == card.value.order(),
+            },
+            Spot::Column(i) => match self.columns[i].last() {
+                None => card.value == Value::King,
+                Some(top) => top.suit.red() != card.suit.red() && top.value.order() == card.value.order() + 1,
+            },
+            _ => false,
+        }
+    }
+
+    pub fn shift(&mut self, from: Spot, to: Spot) -> bool {
+        if from == to {
+            return false;
+        }
+
+        let count = match from {
+            Spot::Stock => return false,
+            Spot::Waste | Spot::Foundation(_) => match self.top(from) {
+                Some(card) if self.fits(card, to) => 1,
+                _ => return false,
+            },
+            Spot::Column(i) => {
+                let column = &self.columns[i];
+                let shown = column.len() - self.hidden[i];
+                let reach = if matches!(to, Spot::Foundation(_)) { shown.min(1) } else { shown };
+                match (1..=reach).rev().find(|&n| self.fits(&column[column.len() - n], to)) {
+                    Some(n) => n,
+                    None => return false,
+                }
+            }
+        };
+
+        let cards = match from {
+            Spot::Waste => vec![self.waste.pop().unwrap()],
+            Spot::Foundation(i) => vec![self.foundations[i].pop().unwrap()],
+            Spot::Column(i) => {
+                let at = self.columns[i].len() - count;
+                let cards = self.columns[i].split_off(at);
+                if self.hidden[i] > 0 && self.hidden[i] == self.columns[i].len() {
+                    self.hidden[i] -= 1;
+                }
+                cards
+            }
+            Spot::Stock => unreachable!(),
+        };
+
+        match to {
+            Spot::Foundation(i) => self.foundations[i].extend(cards),
+            Spot::Column(i) => self.columns[i].extend(cards),
+            _ => unreachable!(),
+        }
+        true
+    }
+
+    pub fn home(&mut self, from: Spot) -> bool {
+        (0..4).any(|i| self.shift(from, Spot::Foundation(i)))
+    }
+
+    pub fn settled(&self) -> bool {
+        self.stock.is_empty() && self.waste.is_empty() && self.hidden.iter().all(|&count| count == 0)
+    }
+
+    pub fn won(&self) -> bool {
+        self.foundations.iter().all(|pile| pile.len() == 13)
+    }
+}
+
+impl Klondike {
+    pub fn new() -> Self {
+        let mut shoe = Shoe::new(1);
+        Self {
+            phase: Phase::Mode,
+            board: Board::new(&mut shoe),
+            shoe,
+            history: Vec::new(),
+            draw: 1,
+            row: 1,
+            col: 0,
+            held: None,
+            moves: 0,
+            message: String::new(),
+        }
+    }
+
+    fn spot(&self) -> Option<Spot> {
+        match (self.row, self.col) {
+            (0, 0) => Some(Spot::Stock),
+            (0, 1) => Some(Spot::Waste),
+            (0, 2) => None,
+            (0, col) => Some(Spot::Foundation(col - 3)),
+            (_, col) => Some(Spot::Column(col)),
+        }
+    }
+
+    fn save(&mut self) {
+        self.history.push(self.board.clone());
+    }
+
+    fn commit(&mut self, moved: bool) {
+        if moved {
+            self.moves += 1;
+            if self.board.won() {
+                self.message = format!("Solved in {} moves", self.moves);
+                self.phase = Phase::Won;
+            }
+        } else {
+            self.history.pop();
+        }
+    }
+
+    fn select(&mut self) {
+        let Some(spot) = self.spot() else {
+            return;
+        };
+
+        if let Some(from) = self.held.take() {
+            self.save();
+            let moved = self.board.shift(from, spot);
+            if !moved {
+                self.message = "Can't move there".into();
+            }
+            self.commit(moved);
+            return;
+        }
+
+        if spot == Spot::Stock {
+            self.save();
+            let moved = self.board.turn(self.draw);
+            self.commit(moved);
+        } else if self.board.top(spot).is_some() {
+            self.held = Some(spot);
+        }
+    }
+
+    fn home(&mut self) {
+        let Some(spot) = self.held.take().or(self.spot()) else {
+            return;
+        };
+        self.save();
+        let moved = self.board.home(spot);
+        self.commit(moved);
+    }
+
+    fn finish(&mut self) {
+        if !self.board.settled() {
+            self.message = "Reveal every card first".into();
+            return;
+        }
+
+        self.save();
+        while (0..7).any(|i| self.board.home(Spot::Column(i))) {
+            self.moves += 1;
+        }
+        self.commit(true);
+    }
+
+    fn undo(&mut self) {
+        if let Some(board) = self.history.pop() {
+            self.board = board;
+            self.held = None;
+            self.moves += 1;
+        }
+    }
+
+    fn handle_mode(&mut self, key: KeyEvent) {
+        match key.code {
+            KeyCode::Char('q') => self.phase = Phase::End,
+            KeyCode::Up | KeyCode::Char('w') | KeyCode::Down | KeyCode::Char('s') => {
+                self.draw = if self.draw == 1 { 3 } else { 1 };
+            }
+            KeyCode::Enter => {
+                self.board = Board::new(&mut self.shoe);
+                self.history.clear();
+                self.moves = 0;
+                self.phase = Phase::Play;
+            }
+            _ => {}
+        }
+    }
+
+    fn handle_play(&mut self, key: KeyEvent) {
+        self.message.clear();
+        match key.code {
+            KeyCode::Char('q') => self.phase = Phase::End,
+            KeyCode::Left | KeyCode::Char('a') => {
+                self.col = self.col.saturating_sub(1);
+                if self.row == 0 && self.col == 2 {
+                    self.col = 1;
+                }
+            }
+            KeyCode::Right | KeyCode::Char('d') => {
+                self.col = (self.col + 1).min(6);
+                if self.row == 0 && self.col == 2 {
+                    self.col = 3;
+                }
+            }
+            KeyCode::Up | KeyCode::Char('w') => {
+                self.row = 0;
+                if self.col == 2 {
+                    self.col = 1;
+                }
+            }
+            KeyCode::Down | KeyCode::Char('s') => self.row = 1,
+            KeyCode::Enter | KeyCode::Char(' ') => self.select(),
+            KeyCode::Char('f') => self.home(),
+            KeyCode::Char('u') => self.undo(),
+            KeyCode::Char('c') => self.finish(),
+            KeyCode::Esc => self.held = None,
+            KeyCode::Char('n') => {
+                self.held = None;
+                self.phase = Phase::Mode;
+            }
+            _ => {}
+        }
+    }
+
+    fn handle_won(&mut self, key: KeyEvent) {
+        match key.code {
+            KeyCode::Char('q') => self.phase = Phase::End,
+            KeyCode::Enter => {
+                self.message.clear();
+                self.phase = Phase::Mode;
+            }
+            _ => {}
+        }
+    }
+
+    fn status(&self, frame: &mut Frame, area: Rect) {
+        let lines = [
+            Line::raw(format!("Draw: {}", self.draw)),
+            Line::raw(format!("Moves: {}", self.moves)),
+            Line::raw(format!("Stock: {}", self.board.stock.len())),
+        ];
+        render_status(frame, &lines, area);
+    }
+
+    fn help(&self, frame: &mut Frame, area: Rect) {
+        let lines = match self.phase {
+            Phase::Mode => vec![
+                Line::raw("[↑↓] Draw 1 / 3"),
+                Line::raw("[Enter] Deal"),
+                Line::raw("[Q] Quit"),
+            ],
+            Phase::Play => vec![
+                Line::raw("[←↑↓→] Move  [Enter] Pick / Drop / Turn stock  [Esc] Drop"),
+                Line::raw("[F] To foundation  [U] Undo  [C] Auto-complete"),
+                Line::raw("[N] New deal  [Q] Quit"),
+            ],
+            Phase::Won => vec![
+                Line::raw("[Enter] New deal"),
+                Line::raw("[Q] Quit"),
+            ],
+            _ => vec![Line::raw("")],
+        };
+        render_help(frame, &lines, area);
+    }
+
+    fn scene(&mut self, frame: &mut Frame, area: Rect) {
+        let width = area.width / 7;
+        let card = width.saturating_sub(2).min(12);
+        let rect = |col: usize, y: u16, height: u16| Rect::new(area.x + col as u16 * width + 1, y, card, height);
+
+        let mut spots = Vec::new();
+
+        let stock = rect(0, area.y, 7);
+        if self.board.stock.is_empty() {
+            render_slot(frame, "↺", stock);
+        } else {
+            render_hidden(frame, stock);
+        }
+        spots.push((Spot::Stock, stock));
+
+        let waste = rect(1, area.y, 7);
+        match self.board.waste.last() {
+            Some(top) => render_card(frame, top, waste),
+            None => render_slot(frame, "", waste),
+        }
+        spots.push((Spot::Waste, waste));
+
+        for (i, pile) in self.board.foundations.iter().enumerate() {
+            let item = rect(i + 3, area.y, 7);
+            match pile.last() {
+                Some(top) => render_card(frame, top, item),
+                None => render_slot(frame, "A", item),
+            }
+            spots.push((Spot::Foundation(i), item));
+        }
+
+        let y = area.y + 8;
+        let height = area.height.saturating_sub(8);
+        for (i, column) in self.board.columns.iter().enumerate() {
+            let item = rect(i, y, height);
+            let top = if column.is_empty() {
+                let slot = rect(i, y, 7.min(height));
+                render_slot(frame, "K", slot);
+                slot
+            } else {
+                render_stack(frame, column, self.board.hidden[i], item)
+            };
+            spots.push((Spot::Column(i), top));
+        }
+
+        for (spot, item) in spots {
+            if Some(spot) == self.held {
+                render_focus(frame, item, Color::Cyan);
+            } else if Some(spot) == self.spot() {
+                render_focus(frame, item, Color::Yellow);
+            }
+        }
+    }
+}
+
+impl Play for Klondike {
+    fn handle(&mut self, key: KeyEvent) {
+        match self.phase {
+            Phase::Mode => self.handle_mode(key),
+            Phase::Play => self.handle_play(key),
+            Phase::Won => self.handle_won(key),
+            _ => {}
+        }
+    }
+
+    fn render(&mut self, frame: &mut Frame, area: Rect) {
+        let inner = area.inner(Margin::new(1, 1));
+
+        let top = Rect::new(inner.x, inner.y, inner.width, 3);
+        let bottom = Rect::new(inner.x, inner.y + inner.height.saturating_sub(4), inner.width, 4);
+        let middle = Rect::new(inner.x, inner.y + 3, inner.width, inner.height.saturating_sub(7));
+
+        self.status(frame, top);
+        self.help(frame, bottom);
+
+        if self.phase != Phase::Mode {
+            self.scene(frame, middle);
+        }
+
+        render_pop(frame, &self.message, inner);
+    }
+
+    fn active(&self) -> bool {
+        self.phase != Phase::End
+    }
+
+    fn prime(&mut self, cards: Vec<Card>) -> Result<(), String> {
+        let mut shoe = Shoe::new(1);
+        shoe.prime(cards)?;
+        self.shoe = shoe;
+        Ok(())
+    }
+}
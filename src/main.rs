@@ -2,15 +2,40 @@
 
 mod blackjack;
 mod card;
+mod chips;
+mod cribbage;
+mod draw;
+mod fair;
+mod gin;
+mod hearts;
 mod holdem;
+mod house;
+mod klondike;
 mod menu;
+mod ofc;
+mod omaha;
+mod paigow;
 mod play;
 mod poker;
+mod reddog;
+mod scenario;
+mod set;
+mod shed;
+mod shuffle;
 mod show;
+mod stud;
+mod table;
+mod trick;
+mod video;
 
 use {
-    crate::{menu::Menu, play::Play},
+    crate::{
+        card::{reseed, roll, seed},
+        menu::Menu,
+        play::Play,
+    },
     crossterm::event::{self, Event},
+    rand::RngExt,
     ratatui::{
         layout::Rect,
         style::{Color, Style},
@@ -20,6 +45,26 @@ use {
 };
 
 fn main() -> std::io::Result<()> {
+    let args: Vec<String> = std::env::args().collect();
+    if args.get(1).is_some_and(|arg| arg == "verify") {
+        verify(args.get(2));
+    }
+    let seed = match args.iter().position(|arg| arg == "--seed" || arg == "-s") {
+        Some(index) => match args.get(index + 1).and_then(|value| value.parse().ok()) {
+            Some(seed) => seed,
+            None => {
+                eprintln!("usage: blankjack [--seed <number>] | blankjack verify <reveal file> | blankjack riffles [trials] [--seed <number>]");
+                std::process::exit(2);
+            }
+        },
+        None => rand::rng().random(),
+    };
+    reseed(seed);
+
+    if args.get(1).is_some_and(|arg| arg == "riffles") {
+        riffles(args.get(2).and_then(|value| value.parse().ok()).unwrap_or(5000));
+    }
+
     let mut terminal = ratatui::init();
     terminal.clear()?;
     let mut game = Menu::new();
@@ -28,13 +73,55 @@ fn main() -> std::io::Result<()> {
     Ok(())
 }
 
+fn verify(path: Option<&String>) -> ! {
+    let Some(path) = path else {
+        eprintln!("usage: blankjack verify <reveal file>");
+        std::process::exit(2);
+    };
+    let result = std::fs::read_to_string(path).map_err(|error| error.to_string()).and_then(|text| fair::verify(&text));
+    match result {
+        Ok((cards, primed)) => {
+            let deal: Vec<String> = cards.iter().map(|card| format!("{:#}", card)).collect();
+            println!("Commitment verified for {} cards", cards.len());
+            if primed > 0 {
+                println!("The first {} cards came from a scenario, ahead of the committed order", primed);
+            }
+            println!("Deal order: {}", deal.join(" "));
+            std::process::exit(0);
+        }
+        Err(error) => {
+            eprintln!("Verification failed: {}", error);
+            std::process::exit(1);
+        }
+    }
+}
+
+fn riffles(trials: usize) -> ! {
+    let mut close = None;
+    println!("Seed {}", seed());
+    println!("Riffles  Rising  Distance");
+    for passes in 1..=12 {
+        let (runs, gap) = roll(|rng| shuffle::distance(passes, 52, trials, rng));
+        println!("{:>7}  {:>6.2}  {:>8.3}", passes, runs, gap);
+        if gap < 0.25 {
+            close = close.or(Some(passes));
+        }
+    }
+    println!("Uniform   26.50     0.000");
+    match close {
+        Some(passes) => println!("Within 0.25 of uniform after {} riffles", passes),
+        None => println!("Not within 0.25 of uniform after 12 riffles"),
+    }
+    std::process::exit(0);
+}
+
 fn run(mut terminal: DefaultTerminal, game: &mut dyn Play) -> std::io::Result<()> {
     while game.active() {
         terminal.draw(|frame| render(frame, game))?;
-        if let Event::Key(key) = event::read()? {
-            if key.kind == event::KeyEventKind::Press {
-                game.handle(key);
-            }
+        if let Event::Key(key) = event::read()?
+            && key.kind == event::KeyEventKind::Press
+        {
+            game.handle(key);
         }
     }
     Ok(())
@@ -44,7 +131,7 @@ fn render(frame: &mut Frame, game: &mut dyn Play) {
     let area = frame.area();
     frame.render_widget(ratatui::widgets::Clear, area);
     let mount = Block::bordered()
-        .title("| Cards |")
+        .title(format!("| Cards | Seed {} |", seed()))
         .border_type(BorderType::Rounded)
         .style(Style::default().fg(Color::Red));
     frame.render_widget(mount, area);
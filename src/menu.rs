@@ -6,22 +6,149 @@ use ratatui::{
     Frame,
 };
 
-use crate::{blackjack::Blackjack, holdem::Holdem, play::Play};
+use crate::{
+    blackjack::Blackjack,
+    card::{reseed, seed},
+    chips::{net, Chips, Wallet, BANKROLL, STEP},
+    cribbage::Cribbage,
+    draw::{Draw, FIVE, TRIPLE},
+    gin::Gin,
+    hearts::Hearts,
+    holdem::Holdem,
+    house::{Caribbean, House, ThreeCard},
+    klondike::Klondike,
+    ofc::Ofc,
+    omaha::Omaha,
+    paigow::PaiGow,
+    play::Play,
+    reddog::RedDog,
+    scenario::{load, Scenario},
+    shed::{Shed, EIGHTS, SWITCH},
+    stud::{Stud, Variant},
+    video::{Video, DEUCES, JOKER},
+};
+
+const RACK: Chips = Chips::dollars(100);
+
+const GAMES: [&str; 21] = [
+    "Blackjack",
+    "Holdem",
+    "Five Card Draw",
+    "2-7 Triple Draw",
+    "Seven Card Stud",
+    "Razz",
+    "Stud Hi/Lo",
+    "Omaha Hi/Lo",
+    "Klondike",
+    "Hearts",
+    "Cribbage",
+    "Gin Rummy",
+    "Crazy Eights",
+    "Switch",
+    "Open-Face Chinese",
+    "Pai Gow Poker",
+    "Caribbean Stud",
+    "Three Card Poker",
+    "Red Dog",
+    "Deuces Wild",
+    "Joker Poker",
+];
 
 pub struct Menu {
     pub game: Option<Box<dyn Play>>,
+    pub scenarios: Vec<Scenario>,
+    pub broken: Vec<String>,
     pub index: usize,
+    pub wallet: Wallet,
+    pub buyin: Chips,
+    pub table: usize,
+    pub bought: [Chips; GAMES.len()],
+    pub cashed: [Chips; GAMES.len()],
+    pub message: String,
     pub run: bool,
 }
 
 impl Menu {
     pub fn new() -> Self {
+        let mut scenarios = Vec::new();
+        let mut broken = Vec::new();
+        for scenario in load("scenarios") {
+            match scenario.and_then(check) {
+                Ok(scenario) => scenarios.push(scenario),
+                Err(error) => broken.push(error),
+            }
+        }
         Self {
             game: None,
+            scenarios,
+            broken,
             index: 0,
+            wallet: Wallet::new(BANKROLL),
+            buyin: RACK,
+            table: 0,
+            bought: [Chips::ZERO; GAMES.len()],
+            cashed: [Chips::ZERO; GAMES.len()],
+            message: String::new(),
             run: true,
         }
     }
+
+    fn count(&self) -> usize {
+        GAMES.len() + self.scenarios.len()
+    }
+
+    fn start(&mut self) {
+        let (table, cards) = match self.scenarios.get(self.index.wrapping_sub(GAMES.len())) {
+            Some(scenario) => match find(&scenario.game) {
+                Some(table) => (table, Some(scenario.cards.clone())),
+                None => return,
+            },
+            None => (self.index, None),
+        };
+
+        reseed(seed());
+        let mut game = launch(table);
+        if let Some(cards) = cards
+            && let Err(error) = game.prime(cards)
+        {
+            self.message = error;
+            return;
+        }
+        if let Some(chips) = game.chips() {
+            let buyin = self.buyin.min(self.wallet.balance());
+            if buyin < STEP {
+                self.message = format!("Need {} to buy in", STEP);
+                return;
+            }
+            let bought = self.bought[table].plus(buyin).and_then(|bought| self.wallet.transfer(chips, buyin).map(|()| bought));
+            match bought {
+                Ok(bought) => self.bought[table] = bought,
+                Err(error) => {
+                    self.message = error.to_string();
+                    return;
+                }
+            }
+        }
+
+        self.message.clear();
+        self.table = table;
+        self.game = Some(game);
+    }
+
+    fn cash(&mut self) {
+        let Some(chips) = self.game.as_mut().and_then(|game| game.chips()) else {
+            return;
+        };
+        let cash = chips.balance();
+        let cashed = self.cashed[self.table].plus(cash).and_then(|cashed| chips.transfer(&mut self.wallet, cash).map(|()| cashed));
+        match cashed {
+            Ok(cashed) => {
+                self.cashed[self.table] = cashed;
+                self.message = format!("Cashed out {}", cash);
+            }
+            Err(error) => self.message = error.to_string(),
+        }
+    }
 }
 
 impl Play for Menu {
@@ -29,6 +156,7 @@ impl Play for Menu {
         if let Some(game) = &mut self.game {
             game.handle(key);
             if !game.active() {
+                self.cash();
                 self.game = None;
             }
             return;
@@ -36,15 +164,19 @@ impl Play for Menu {
 
         match key.code {
             KeyCode::Char('q') => self.run = false,
-            KeyCode::Up | KeyCode::Char('w') => self.index = 0,
-            KeyCode::Down | KeyCode::Char('s') => self.index = 1,
-            KeyCode::Enter => {
-                if self.index == 0 {
-                    self.game = Some(Box::new(Blackjack::new()));
-                } else {
-                    self.game = Some(Box::new(Holdem::new()));
-                }
+            KeyCode::Up | KeyCode::Char('w') => {
+                self.index = (self.index + self.count() - 1) % self.count();
+            }
+            KeyCode::Down | KeyCode::Char('s') => {
+                self.index = (self.index + 1) % self.count();
             }
+            KeyCode::Right | KeyCode::Char('d') => {
+                self.buyin = self.buyin.raise(RACK, self.wallet.balance().max(RACK));
+            }
+            KeyCode::Left | KeyCode::Char('a') => {
+                self.buyin = self.buyin.lower(RACK, RACK);
+            }
+            KeyCode::Enter => self.start(),
             _ => {}
         }
     }
@@ -55,13 +187,31 @@ impl Play for Menu {
             return;
         }
 
-        let lines = vec![
-            Line::raw(if self.index == 0 { "> Blackjack" } else { "  Blackjack" }),
-            Line::raw(if self.index == 1 { "> Holdem" } else { "  Holdem" }),
+        let mut lines = vec![
+            Line::raw(format!("Casino: {}  Buy-in: {} [←→]", self.wallet.balance(), self.buyin.min(self.wallet.balance()))),
+            Line::raw(self.message.clone()),
         ];
+        lines.extend(GAMES.iter().enumerate().map(|(i, name)| {
+            let marker = if i == self.index { ">" } else { " " };
+            if self.bought[i].is_zero() {
+                Line::raw(format!("{} {}", marker, name))
+            } else {
+                Line::raw(format!("{} {} ({})", marker, name, net(self.cashed[i], self.bought[i])))
+            }
+        }));
+        if !self.scenarios.is_empty() || !self.broken.is_empty() {
+            lines.push(Line::raw(""));
+            lines.push(Line::raw("Scenarios"));
+        }
+        for (i, scenario) in self.scenarios.iter().enumerate() {
+            let marker = if GAMES.len() + i == self.index { ">" } else { " " };
+            lines.push(Line::raw(format!("{} {}", marker, scenario.name)));
+        }
+        lines.extend(self.broken.iter().map(|error| Line::raw(format!("! {}", error))));
 
+        let height = lines.len() as u16;
         let item = Paragraph::new(lines).alignment(Alignment::Center);
-        let spot = Rect::new(area.x, area.y + area.height / 2, area.width, 2);
+        let spot = Rect::new(area.x, area.y + area.height.saturating_sub(height) / 2, area.width, height);
 
         frame.render_widget(Clear, area);
         frame.render_widget(item, spot);
@@ -71,3 +221,44 @@ impl Play for Menu {
         self.run
     }
 }
+
+fn find(game: &str) -> Option<usize> {
+    GAMES.iter().position(|name| name.eq_ignore_ascii_case(game))
+}
+
+fn check(scenario: Scenario) -> Result<Scenario, String> {
+    let primed = match find(&scenario.game) {
+        Some(table) => launch(table).prime(scenario.cards.clone()),
+        None => Err(format!("unknown game {}", scenario.game)),
+    };
+    match primed {
+        Ok(()) => Ok(scenario),
+        Err(error) => Err(format!("{}: {}", scenario.name, error)),
+    }
+}
+
+fn launch(index: usize) -> Box<dyn Play> {
+    match index {
+        0 => Box::new(Blackjack::new()),
+        1 => Box::new(Holdem::new()),
+        2 => Box::new(Draw::new(FIVE)),
+        3 => Box::new(Draw::new(TRIPLE)),
+        4 => Box::new(Stud::new(Variant::High)),
+        5 => Box::new(Stud::new(Variant::Razz)),
+        6 => Box::new(Stud::new(Variant::Eight)),
+        7 => Box::new(Omaha::new()),
+        8 => Box::new(Klondike::new()),
+        9 => Box::new(Hearts::new()),
+        10 => Box::new(Cribbage::new()),
+        11 => Box::new(Gin::new()),
+        12 => Box::new(Shed::new(EIGHTS)),
+        13 => Box::new(Shed::new(SWITCH)),
+        14 => Box::new(Ofc::new()),
+        15 => Box::new(PaiGow::new()),
+        16 => Box::new(House::new(Box::new(Caribbean))),
+        17 => Box::new(House::new(Box::new(ThreeCard))),
+        18 => Box::new(RedDog::new()),
+        19 => Box::new(Video::new(DEUCES)),
+        _ => Box::new(Video::new(JOKER)),
+    }
+}
@@ -0,0 +1,414 @@
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::{
+    layout::{Margin, Rect},
+    text::Line,
+    Frame,
+};
+
+use crate::{
+    card::{Card, Hand, Shoe},
+    play::Play,
+    poker::{rate, Rank, Score},
+    show::{render_grid, render_help, render_pick, render_pop, render_status},
+};
+
+const SIZES: [usize; 3] = [3, 5, 5];
+const ROWS: [&str; 3] = ["Top", "Middle", "Bottom"];
+
+#[derive(PartialEq)]
+pub enum Phase {
+    Place,
+    Result,
+    End,
+}
+
+#[derive(Clone, Default)]
+pub struct Board {
+    pub rows: [Vec<Card>; 3],
+}
+
+impl Board {
+    pub fn room(&self, row: usize) -> bool {
+        self.rows[row].len() < SIZES[row]
+    }
+
+    pub fn full(&self) -> bool {
+        (0..3).all(|row| !self.room(row))
+    }
+
+    pub fn empty(&self) -> bool {
+        self.rows.iter().all(|row| row.is_empty())
+    }
+
+    pub fn scores(&self) -> [Score; 3] {
+        [rate(&self.rows[0]), rate(&self.rows[1]), rate(&self.rows[2])]
+    }
+
+    pub fn fouled(&self) -> bool {
+        let [top, middle, bottom] = self.scores();
+        top > middle || middle > bottom
+    }
+
+    pub fn royalties(&self) -> u32 {
+        if self.fouled() {
+            return 0;
+        }
+        self.scores().iter().enumerate().map(|(row, &score)| royalty(row, score)).sum()
+    }
+
+    pub fn earns(&self) -> bool {
+        let top = rate(&self.rows[0]);
+        !self.fouled() && (top.rank == Rank::Three || (top.rank == Rank::Pair && top.top() >= 12))
+    }
+
+    pub fn stays(&self) -> bool {
+        let [top, middle, bottom] = self.scores();
+        !self.fouled() && (top.rank == Rank::Three || middle.rank >= Rank::FullHouse || bottom.rank >= Rank::Four)
+    }
+}
+
+pub struct Ofc {
+    pub phase: Phase,
+    pub shoe: Shoe,
+    pub boards: [Board; 2],
+    pub fantasy: [bool; 2],
+    pub pending: Hand,
+    pub placed: Vec<usize>,
+    pub keep: usize,
+    pub cursor: usize,
+    pub row: usize,
+    pub points: i32,
+    pub message: String,
+}
+
+impl Ofc {
+    pub fn new() -> Self {
+        let mut game = Self {
+            phase: Phase::Place,
+            shoe: Shoe::new(1),
+            boards: [Board::default(), Board::default()],
+            fantasy: [false; 2],
+            pending: Hand::new(),
+            placed: Vec::new(),
+            keep: 0,
+            cursor: 0,
+            row: 2,
+            points: 0,
+            message: String::new(),
+        };
+        game.deal();
+        game
+    }
+
+    fn deal(&mut self) {
+        self.shoe.restock(Shoe::new(1));
+        self.shoe.shuffle();
+        self.boards = [Board::default(), Board::default()];
+        self.message.clear();
+        self.phase = Phase::Place;
+        self.next();
+    }
+
+    fn draw(&mut self, seat: usize) -> (Vec<Card>, usize) {
+        let (count, keep) = if self.fantasy[seat] {
+            (14, 13)
+        } else if self.boards[seat].empty() {
+            (5, 5)
+        } else {
+            (3, 2)
+        };
+        ((0..count).filter_map(|_| self.shoe.draw()).collect(), keep)
+    }
+
+    fn next(&mut self) {
+        if self.boards[0].full() {
+            while !self.boards[1].full() {
+                self.bot();
+            }
+            self.showdown();
+            return;
+        }
+
+        if !self.boards[1].full() {
+            self.bot();
+        }
+
+        let (cards, keep) = self.draw(0);
+        self.pending = Hand { cards };
+        self.keep = keep;
+        self.placed.clear();
+        self.cursor = 0;
+    }
+
+    fn bot(&mut self) {
+        let (cards, keep) = self.draw(1);
+        self.boards[1] = arrange(&self.boards[1], &cards, keep);
+    }
+
+    fn showdown(&mut self) {
+        let net = settle(&self.boards[0], &self.boards[1]);
+        self.points += net;
+
+        let mine = if self.boards[0].fouled() { "You fouled".to_string() } else { format!("You {:+}", net) };
+        let theirs = if self.boards[1].fouled() { ", bot fouled" } else { "" };
+        self.message = format!("{}{}", mine, theirs);
+
+        for seat in 0..2 {
+            let board = &self.boards[seat];
+            self.fantasy[seat] = if self.fantasy[seat] { board.stays() } else { board.earns() };
+        }
+        if self.fantasy[0] {
+            self.message.push_str(", fantasyland!");
+        }
+
+        self.phase = Phase::Result;
+    }
+
+    fn handle_place(&mut self, key: KeyEvent) {
+        let last = self.pending.cards.len().saturating_sub(1);
+        match key.code {
+            KeyCode::Char('q') => self.phase = Phase::End,
+            KeyCode::Left | KeyCode::Char('a') => self.cursor = self.cursor.saturating_sub(1),
+            KeyCode::Right | KeyCode::Char('d') => self.cursor = (self.cursor + 1).min(last),
+            KeyCode::Up | KeyCode::Char('w') => self.row = self.row.saturating_sub(1),
+            KeyCode::Down | KeyCode::Char('s') => self.row = (self.row + 1).min(2),
+            KeyCode::Enter | KeyCode::Char(' ') => {
+                if !self.boards[0].room(self.row) {
+                    self.message = format!("{} row is full", ROWS[self.row]);
+                    return;
+                }
+                self.message.clear();
+                let card = self.pending.cards.remove(self.cursor);
+                self.boards[0].rows[self.row].push(card);
+                self.placed.push(self.row);
+                self.cursor = self.cursor.min(self.pending.cards.len().saturating_sub(1));
+
+                if self.placed.len() == self.keep {
+                    self.pending.cards.clear();
+                    self.next();
+                }
+            }
+            KeyCode::Char('u') => {
+                if let Some(row) = self.placed.pop() {
+                    let card = self.boards[0].rows[row].pop().unwrap();
+                    self.pending.add(card);
+                    self.cursor = self.pending.cards.len() - 1;
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn handle_result(&mut self, key: KeyEvent) {
+        match key.code {
+            KeyCode::Char('q') => self.phase = Phase::End,
+            KeyCode::Enter => self.deal(),
+            _ => {}
+        }
+    }
+
+    fn status(&self, frame: &mut Frame, area: Rect) {
+        let lines = [
+            Line::raw(format!("Points: {:+}", self.points)),
+            Line::raw(format!("Place: {} of {}", self.placed.len(), self.keep)),
+            Line::raw(if self.fantasy[0] { "Fantasyland" } else { "" }),
+        ];
+        render_status(frame, &lines, area);
+    }
+
+    fn help(&self, frame: &mut Frame, area: Rect) {
+        let lines = match self.phase {
+            Phase::Place => vec![
+                Line::raw("[←→] Card  [↑↓] Row"),
+                Line::raw("[Enter] Place"),
+                Line::raw("[U] Undo"),
+                Line::raw("[Q] Quit"),
+            ],
+            Phase::Result => vec![
+                Line::raw("[Enter] Next hand"),
+                Line::raw("[Q] Quit"),
+            ],
+            _ => vec![Line::raw("")],
+        };
+        render_help(frame, &lines, area);
+    }
+
+    fn scene(&mut self, frame: &mut Frame, area: Rect) {
+        let half = area.width / 2;
+        let height = area.height.saturating_sub(8).min(22);
+        let names = ["You", "Bot"];
+
+        for (seat, x) in [(0, area.x + half), (1, area.x)] {
+            let board = &self.boards[seat];
+            let mut label = names[seat].to_string();
+            if self.fantasy[seat] && self.phase == Phase::Place {
+                label.push_str(" (Fantasyland)");
+            }
+            if self.phase == Phase::Result {
+                if board.fouled() {
+                    label.push_str(" - Fouled");
+                } else {
+                    let ranks: Vec<String> = board.scores().iter().map(|score| score.rank.to_string()).collect();
+                    label = format!("{} - {} - Royalties {}", label, ranks.join(" / "), board.royalties());
+                }
+            }
+            frame.render_widget(Line::raw(label).centered(), Rect::new(x, area.y, half, 1));
+
+            let cursor = (seat == 0 && self.phase == Phase::Place).then_some(self.row);
+            render_grid(frame, &board.rows, &SIZES, Rect::new(x, area.y + 1, half, height), cursor);
+        }
+
+        let hand = Rect::new(area.x, area.y + area.height.saturating_sub(7), area.width, 7.min(area.height));
+        if self.phase == Phase::Place {
+            render_pick(frame, &self.pending, hand, self.cursor, &[]);
+        }
+    }
+}
+
+pub fn royalty(row: usize, score: Score) -> u32 {
+    let top = score.top() as u32;
+    let royal = score.rank == Rank::StraightFlush && score.power == 14;
+    match (row, score.rank) {
+        (0, Rank::Three) => top + 8,
+        (0, Rank::Pair) if top >= 6 => top - 5,
+        (1, Rank::Three) => 2,
+        (1, Rank::Straight) => 4,
+        (1, Rank::Flush) => 8,
+        (1, Rank::FullHouse) => 12,
+        (1, Rank::Four) => 20,
+        (1, Rank::StraightFlush) => if royal { 50 } else { 30 },
+        (2, Rank::Straight) => 2,
+        (2, Rank::Flush) => 4,
+        (2, Rank::FullHouse) => 6,
+        (2, Rank::Four) => 10,
+        (2, Rank::StraightFlush) => if royal { 25 } else { 15 },
+        _ => 0,
+    }
+}
+
+pub fn settle(mine: &Board, theirs: &Board) -> i32 {
+    match (mine.fouled(), theirs.fouled()) {
+        (true, true) => 0,
+        (true, false) => -6 - theirs.royalties() as i32,
+        (false, true) => 6 + mine.royalties() as i32,
+        (false, false) => {
+            let rows: i32 = mine.scores().iter().zip(theirs.scores()).map(|(a, b)| a.cmp(&b) as i32).sum();
+            let scoop = if rows.abs() == 3 { rows.signum() * 3 } else { 0 };
+            rows + scoop + mine.royalties() as i32 - theirs.royalties() as i32
+        }
+    }
+}
+
+pub fn arrange(board: &Board, cards: &[Card], keep: usize) -> Board {
+    if keep > 5 {
+        let mut sorted = cards.to_vec();
+        sorted.sort_by_key(|card| std::cmp::Reverse(card.value.score()));
+        let mut board = board.clone();
+        for &card in sorted.iter().take(keep) {
+            board = (0..3)
+                .filter(|&row| board.room(row))
+                .map(|row| {
+                    let mut next = board.clone();
+                    next.rows[row].push(card);
+                    next
+                })
+                .max_by(|a, b| value(a).total_cmp(&value(b)))
+                .unwrap();
+        }
+        return board;
+    }
+
+    let mut best = (f32::MIN, board.clone());
+    search(&mut board.clone(), cards, cards.len() - keep, &mut best);
+    best.1
+}
+
+fn search(board: &mut Board, cards: &[Card], skips: usize, best: &mut (f32, Board)) {
+    let Some((&card, rest)) = cards.split_first() else {
+        let score = value(board);
+        if skips == 0 && score > best.0 {
+            *best = (score, board.clone());
+        }
+        return;
+    };
+
+    if skips > 0 {
+        search(board, rest, skips - 1, best);
+    }
+    for row in 0..3 {
+        if board.room(row) {
+            board.rows[row].push(card);
+            search(board, rest, skips, best);
+            board.rows[row].pop();
+        }
+    }
+}
+
+fn value(board: &Board) -> f32 {
+    if board.full() {
+        return if board.fouled() { -10.0 } else { board.royalties() as f32 * 0.5 + board.earns() as u8 as f32 };
+    }
+
+    let weights = [0.6, 0.9, 1.2];
+    let scores = board.scores();
+    let mut total = 0.0;
+
+    for row in 0..3 {
+        if board.rows[row].is_empty() {
+            continue;
+        }
+        total += scores[row].strength() * weights[row];
+
+        let suit = board.rows[row][0].suit;
+        if row > 0 && board.rows[row].len() >= 2 && board.rows[row].iter().all(|card| card.suit == suit) {
+            total += 0.05 * board.rows[row].len() as f32;
+        }
+    }
+
+    for (upper, lower) in [(0, 1), (1, 2)] {
+        if board.rows[upper].is_empty() || board.rows[lower].is_empty() {
+            continue;
+        }
+        if scores[upper] > scores[lower] {
+            total -= if board.room(lower) { 0.6 } else { 5.0 };
+        }
+    }
+
+    total
+}
+
+impl Play for Ofc {
+    fn handle(&mut self, key: KeyEvent) {
+        match self.phase {
+            Phase::Place => self.handle_place(key),
+            Phase::Result => self.handle_result(key),
+            _ => {}
+        }
+    }
+
+    fn render(&mut self, frame: &mut Frame, area: Rect) {
+        let inner = area.inner(Margin::new(1, 1));
+
+        let top = Rect::new(inner.x, inner.y, inner.width, 3);
+        let bottom = Rect::new(inner.x, inner.y + inner.height.saturating_sub(4), inner.width, 4);
+        let middle = Rect::new(inner.x, inner.y + 3, inner.width, inner.height.saturating_sub(7));
+
+        self.status(frame, top);
+        self.help(frame, bottom);
+        self.scene(frame, middle);
+
+        render_pop(frame, &self.message, inner);
+    }
+
+    fn active(&self) -> bool {
+        self.phase != Phase::End
+    }
+
+    fn prime(&mut self, cards: Vec<Card>) -> Result<(), String> {
+        let mut shoe = Shoe::new(1);
+        shoe.prime(cards)?;
+        self.shoe = shoe;
+        self.deal();
+        Ok(())
+    }
+}
@@ -0,0 +1,288 @@
+use crossterm::event::KeyEvent;
+use ratatui::{
+    layout::{Margin, Rect},
+    text::Line,
+    Frame,
+};
+
+use crate::{
+    card::{Card, Hand, Shoe},
+    chips::{Chips, Error, Wallet},
+    play::Play,
+    poker::{ace_five, evaluate, omaha_high, omaha_low},
+    show::{render_backs, render_hand, render_help, render_pop, render_status},
+    table::{Round, Seat, Step, Table},
+};
+
+#[derive(PartialEq)]
+pub enum Phase {
+    Ante,
+    Preflop,
+    Flop,
+    Turn,
+    River,
+    Result,
+    End,
+}
+
+pub struct Omaha {
+    pub phase: Phase,
+    pub shoe: Shoe,
+    pub table: Table,
+    pub board: Hand,
+    pub button: usize,
+    pub message: String,
+}
+
+impl Omaha {
+    pub fn new() -> Self {
+        let seats = vec![
+            Seat::new("You"),
+            Seat::new("Bot 1"),
+            Seat::new("Bot 2"),
+            Seat::new("Bot 3"),
+        ];
+        Self {
+            phase: Phase::Ante,
+            shoe: Shoe::new(1),
+            table: Table::new(seats),
+            board: Hand::new(),
+            button: 0,
+            message: String::new(),
+        }
+    }
+
+    fn deal(&mut self) -> Result<(), Error> {
+        self.message.clear();
+        self.table.reset();
+        self.board.cards.clear();
+        self.shoe.restock(Shoe::new(1));
+        self.shoe.shuffle();
+
+        let bet = self.table.bet;
+        self.table.refill(bet.times(6)?)?;
+        self.table.ante(bet.split(5).0.max(Chips::dollars(1)))?;
+
+        for _ in 0..4 {
+            for seat in &mut self.table.seats {
+                seat.hand.add(self.shoe.draw().unwrap());
+            }
+        }
+
+        self.button = self.table.next(self.button);
+        self.table.open(self.table.next(self.button), bet);
+        self.phase = Phase::Preflop;
+        self.flow()
+    }
+
+    fn street(&mut self, phase: Phase, cards: usize) -> Result<(), Error> {
+        let bet = self.table.bet;
+        let size = if phase == Phase::Flop { bet } else { bet.times(2)? };
+        for _ in 0..cards {
+            self.board.add(self.shoe.draw().unwrap());
+        }
+        self.phase = phase;
+        self.table.open(self.table.next(self.button), size);
+        Ok(())
+    }
+
+    fn flow(&mut self) -> Result<(), Error> {
+        loop {
+            let board = self.board.clone();
+            match self.table.walk(|hand| strength(hand, &board))? {
+                Round::Wait => return Ok(()),
+                Round::Over => return self.showdown(),
+                Round::Done => {}
+            }
+
+            match self.phase {
+                Phase::Preflop => self.street(Phase::Flop, 3)?,
+                Phase::Flop => self.street(Phase::Turn, 1)?,
+                Phase::Turn => self.street(Phase::River, 1)?,
+                _ => return self.showdown(),
+            }
+        }
+    }
+
+    fn showdown(&mut self) -> Result<(), Error> {
+        self.phase = Phase::Result;
+        if let Some(text) = self.table.take()? {
+            self.message = text;
+            return Ok(());
+        }
+
+        let live = self.table.players();
+        let highs = self.table.best(&live, |hand| omaha_high(hand, &self.board));
+        let lows = self.lows(&live);
+
+        let high = |winners: &[usize]| omaha_high(&self.table.seats[winners[0]].hand, &self.board).rank.to_string();
+        let low = |winners: &[usize]| omaha_low(&self.table.seats[winners[0]].hand, &self.board).unwrap().to_string();
+        let names = |winners: &[usize]| self.table.names(winners);
+
+        self.message = if lows.is_empty() {
+            format!("{} wins with {}, no low", names(&highs), high(&highs))
+        } else if highs == lows {
+            format!("{} scoops", names(&highs))
+        } else {
+            format!("High: {} {}, Low: {} {}", names(&highs), high(&highs), names(&lows), low(&lows))
+        };
+
+        self.table.split(&highs, &lows)
+    }
+
+    fn lows(&self, live: &[usize]) -> Vec<usize> {
+        let score = |hand: &Hand| omaha_low(hand, &self.board);
+        let best = self.table.best(live, score);
+        if best.first().is_some_and(|&i| score(&self.table.seats[i].hand).is_none()) { Vec::new() } else { best }
+    }
+
+    fn handle_ante(&mut self, key: KeyEvent) {
+        match self.table.wager(key) {
+            Step::Quit => self.phase = Phase::End,
+            Step::Deal => {
+                if let Err(error) = self.deal() {
+                    self.message = error.to_string();
+                }
+            }
+            Step::Short(text) => self.message = text,
+            _ => {}
+        }
+    }
+
+    fn handle_action(&mut self, key: KeyEvent) {
+        match self.table.respond(key) {
+            Step::Acted => {
+                if let Err(error) = self.flow() {
+                    self.message = error.to_string();
+                }
+            }
+            Step::Short(text) => self.message = text,
+            _ => {}
+        }
+    }
+
+    fn handle_result(&mut self, key: KeyEvent) {
+        match self.table.rebet(key) {
+            Step::Quit => self.phase = Phase::End,
+            Step::Next => {
+                self.message.clear();
+                self.phase = Phase::Ante;
+            }
+            _ => {}
+        }
+    }
+
+    fn status(&self, frame: &mut Frame, area: Rect) {
+        render_status(frame, &self.table.status(), area);
+    }
+
+    fn help(&self, frame: &mut Frame, area: Rect) {
+        let lines = match self.phase {
+            Phase::Ante => vec![
+                Line::raw("[↑↓] Bet"),
+                Line::raw("[Enter] Deal"),
+                Line::raw("[Q] Quit"),
+            ],
+            Phase::Result => vec![
+                Line::raw("[Enter] Next"),
+                Line::raw("[Q] Quit"),
+            ],
+            Phase::End => vec![Line::raw("")],
+            _ => self.table.prompt(),
+        };
+        render_help(frame, &lines, area);
+    }
+
+    fn scene(&mut self, frame: &mut Frame, area: Rect) {
+        let chunk = area.height / 3;
+        let bots = self.table.seats.len() as u16 - 1;
+        let width = area.width / bots;
+        let reveal = self.phase == Phase::Result && self.table.live() > 1;
+
+        for (i, seat) in self.table.seats.iter().enumerate().skip(1) {
+            let x = area.x + (i as u16 - 1) * width;
+            let label = Rect::new(x + 2, area.y, width.saturating_sub(2), 1);
+            let cards = Rect::new(x, area.y + 1, width, chunk.saturating_sub(1));
+
+            frame.render_widget(Line::raw(self.table.label(i)), label);
+
+            if seat.folded {
+                continue;
+            }
+            if reveal {
+                render_hand(frame, &seat.hand, cards, false);
+            } else {
+                render_backs(frame, seat.hand.cards.len(), cards);
+            }
+        }
+
+        let board = Rect::new(area.x, area.y + chunk, area.width, chunk);
+        render_hand(frame, &self.board, board, false);
+
+        let player = &self.table.seats[0];
+        let label = Rect::new(area.x + 2, area.y + chunk * 2, area.width.saturating_sub(2), 1);
+        let cards = Rect::new(area.x, area.y + chunk * 2 + 1, area.width, chunk.saturating_sub(1));
+
+        let rank = if self.board.cards.len() < 3 {
+            String::new()
+        } else {
+            match omaha_low(&player.hand, &self.board) {
+                Some(low) => format!("{} / {}", omaha_high(&player.hand, &self.board).rank, low),
+                None => omaha_high(&player.hand, &self.board).rank.to_string(),
+            }
+        };
+        frame.render_widget(Line::raw(format!("{}: {} {}", player.name, rank, player.last)), label);
+        render_hand(frame, &player.hand, cards, false);
+    }
+}
+
+fn strength(hand: &Hand, board: &Hand) -> f32 {
+    if board.cards.len() < 3 {
+        let high = evaluate(hand, &Hand::new()).strength();
+        let low = ace_five(&hand.cards).strength();
+        return high.max(low) + 0.15;
+    }
+    let high = omaha_high(hand, board).strength();
+    let low = omaha_low(hand, board).map_or(0.0, |low| low.strength());
+    high.max(low)
+}
+
+impl Play for Omaha {
+    fn handle(&mut self, key: KeyEvent) {
+        match self.phase {
+            Phase::Ante => self.handle_ante(key),
+            Phase::Result => self.handle_result(key),
+            Phase::End => {}
+            _ => self.handle_action(key),
+        }
+    }
+
+    fn render(&mut self, frame: &mut Frame, area: Rect) {
+        let inner = area.inner(Margin::new(1, 1));
+
+        let top = Rect::new(inner.x, inner.y, inner.width, 4);
+        let bottom = Rect::new(inner.x, inner.y + inner.height.saturating_sub(5), inner.width, 5);
+        let middle = Rect::new(inner.x, inner.y + 4, inner.width, inner.height.saturating_sub(9));
+
+        self.status(frame, top);
+        self.help(frame, bottom);
+
+        if self.phase != Phase::Ante {
+            self.scene(frame, middle);
+        }
+
+        render_pop(frame, &self.message, inner);
+    }
+
+    fn active(&self) -> bool {
+        self.phase != Phase::End
+    }
+
+    fn chips(&mut self) -> Option<&mut Wallet> {
+        Some(&mut self.table.seats[0].money)
+    }
+
+    fn prime(&mut self, cards: Vec<Card>) -> Result<(), String> {
+        self.shoe.prime(cards)
+    }
+}
@@ -0,0 +1,358 @@
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::{
+    layout::{Margin, Rect},
+    text::Line,
+    Frame,
+};
+
+use crate::{
+    card::{Card, Hand, Shoe, Value},
+    chips::{net, Chips, Error, Round, Wallet, HOUSE, STEP},
+    play::Play,
+    poker::{bug, Rank, Score},
+    show::{render_backs, render_hand, render_help, render_pick, render_pop, render_status},
+};
+
+#[derive(PartialEq)]
+pub enum Phase {
+    Bet,
+    Set,
+    Result,
+    End,
+}
+
+#[derive(Clone)]
+pub struct Split {
+    pub high: Hand,
+    pub low: Hand,
+}
+
+impl Split {
+    pub fn scores(&self) -> (Score, Score) {
+        (bug(&self.high.cards), bug(&self.low.cards))
+    }
+
+    pub fn legal(&self) -> bool {
+        let (high, low) = self.scores();
+        high > low
+    }
+}
+
+pub struct PaiGow {
+    pub phase: Phase,
+    pub shoe: Shoe,
+    pub player: Hand,
+    pub dealer: Hand,
+    pub mine: Option<Split>,
+    pub house: Option<Split>,
+    pub cursor: usize,
+    pub marks: Vec<bool>,
+    pub money: Wallet,
+    pub bank: Wallet,
+    pub bet: Chips,
+    pub stake: Chips,
+    pub message: String,
+}
+
+impl PaiGow {
+    pub fn new() -> Self {
+        Self {
+            phase: Phase::Bet,
+            shoe: Shoe::jokers(1, 1),
+            player: Hand::new(),
+            dealer: Hand::new(),
+            mine: None,
+            house: None,
+            cursor: 0,
+            marks: vec![false; 7],
+            money: Wallet::new(Chips::ZERO),
+            bank: Wallet::new(HOUSE),
+            bet: STEP,
+            stake: Chips::ZERO,
+            message: String::new(),
+        }
+    }
+
+    fn deal(&mut self) -> Result<(), Error> {
+        self.money.transfer(&mut self.bank, self.bet)?;
+        self.stake = self.bet;
+        self.shoe.restock(Shoe::jokers(1, 1));
+        self.shoe.shuffle();
+        self.player = Hand::new();
+        self.dealer = Hand::new();
+        for _ in 0..7 {
+            self.player.add(self.shoe.draw().unwrap());
+            self.dealer.add(self.shoe.draw().unwrap());
+        }
+        sort(&mut self.player);
+        sort(&mut self.dealer);
+        self.mine = None;
+        self.house = None;
+        self.cursor = 0;
+        self.marks = vec![false; 7];
+        self.message.clear();
+        Ok(())
+    }
+
+    fn settle(&mut self, mine: Split) -> Result<(), Error> {
+        let house = house(&self.dealer);
+        let (high, low) = mine.scores();
+        let (top, bottom) = house.scores();
+
+        let wins = (high > top) as u8 + (low > bottom) as u8;
+        let losses = (high <= top) as u8 + (low <= bottom) as u8;
+
+        let paid = if wins == 2 {
+            let commission = self.stake.ratio(5, 100, Round::Up)?;
+            let paid = self.stake.times(2)?.minus(commission)?;
+            self.message = format!("Win after {} commission ({})", commission, net(paid, self.stake));
+            paid
+        } else if losses == 2 {
+            self.message = format!("Lose ({})", net(Chips::ZERO, self.stake));
+            Chips::ZERO
+        } else {
+            self.message = "Push".into();
+            self.stake
+        };
+        self.bank.transfer(&mut self.money, paid)?;
+
+        if self.money.broke() {
+            self.message.push_str(" Bankrupt");
+        }
+
+        self.mine = Some(mine);
+        self.house = Some(house);
+        self.phase = Phase::Result;
+        Ok(())
+    }
+
+    fn handle_bet(&mut self, key: KeyEvent) {
+        match key.code {
+            KeyCode::Char('q') => self.phase = Phase::End,
+            KeyCode::Up | KeyCode::Char('w') => {
+                self.bet = self.bet.raise(STEP, self.money.balance());
+            }
+            KeyCode::Down | KeyCode::Char('s') => {
+                self.bet = self.bet.lower(STEP, STEP);
+            }
+            KeyCode::Enter => match self.deal() {
+                Ok(()) => self.phase = Phase::Set,
+                Err(error) => self.message = error.to_string(),
+            },
+            _ => {}
+        }
+    }
+
+    fn handle_set(&mut self, key: KeyEvent) {
+        match key.code {
+            KeyCode::Left | KeyCode::Char('a') => self.cursor = self.cursor.saturating_sub(1),
+            KeyCode::Right | KeyCode::Char('d') => self.cursor = (self.cursor + 1).min(6),
+            KeyCode::Char(' ') => {
+                let count = self.marks.iter().filter(|&&mark| mark).count();
+                if self.marks[self.cursor] || count < 2 {
+                    self.marks[self.cursor] = !self.marks[self.cursor];
+                }
+            }
+            KeyCode::Char('h') => {
+                let split = house(&self.player);
+                self.marks = self.player.cards.iter().map(|card| split.low.cards.contains(card)).collect();
+            }
+            KeyCode::Enter => {
+                if self.marks.iter().filter(|&&mark| mark).count() != 2 {
+                    self.message = "Pick two cards for the low hand".into();
+                    return;
+                }
+                let split = divide(&self.player, &self.marks);
+                if !split.legal() {
+                    self.message = "High hand must beat the low hand".into();
+                    return;
+                }
+                self.message.clear();
+                if let Err(error) = self.settle(split) {
+                    self.message = error.to_string();
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn handle_result(&mut self, key: KeyEvent) {
+        match key.code {
+            KeyCode::Char('q') => self.phase = Phase::End,
+            KeyCode::Enter => {
+                if self.money.broke() {
+                    self.phase = Phase::End;
+                } else {
+                    self.bet = self.bet.min(self.money.balance());
+                    self.message.clear();
+                    self.phase = Phase::Bet;
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn status(&self, frame: &mut Frame, area: Rect) {
+        let lines = [
+            Line::raw(format!("Money: {}", self.money.balance())),
+            Line::raw(format!("Bet: {}", self.bet)),
+        ];
+        render_status(frame, &lines, area);
+    }
+
+    fn help(&self, frame: &mut Frame, area: Rect) {
+        let lines = match self.phase {
+            Phase::Bet => vec![
+                Line::raw("[↑↓] Bet"),
+                Line::raw("[Enter] Deal"),
+                Line::raw("[Q] Quit"),
+            ],
+            Phase::Set => vec![
+                Line::raw("[←→] Move  [Space] Low hand"),
+                Line::raw("[H] House way"),
+                Line::raw("[Enter] Set"),
+            ],
+            Phase::Result => vec![
+                Line::raw("[Enter] Next"),
+                Line::raw("[Q] Quit"),
+            ],
+            _ => vec![Line::raw("")],
+        };
+        render_help(frame, &lines, area);
+    }
+
+    fn scene(&mut self, frame: &mut Frame, area: Rect) {
+        let chunk = area.height / 2;
+        let dealer = Rect::new(area.x, area.y, area.width, chunk);
+        let player = Rect::new(area.x, area.y + chunk, area.width, chunk);
+
+        match (&self.mine, &self.house) {
+            (Some(mine), Some(house)) => {
+                reveal(frame, "Dealer", house, dealer);
+                reveal(frame, "You", mine, player);
+            }
+            _ => {
+                render_backs(frame, 7, dealer);
+                render_pick(frame, &self.player, player, self.cursor, &self.marks);
+                let split = divide(&self.player, &self.marks);
+                if self.marks.iter().filter(|&&mark| mark).count() == 2 {
+                    let (high, low) = split.scores();
+                    let label = format!("High: {}  Low: {}", high.rank, low.rank);
+                    let spot = Rect::new(player.x + 2, player.y, player.width.saturating_sub(2), 1);
+                    frame.render_widget(Line::raw(label), spot);
+                }
+            }
+        }
+    }
+}
+
+fn reveal(frame: &mut Frame, label: &str, split: &Split, area: Rect) {
+    let (high, low) = split.scores();
+    let width = area.width * 5 / 7;
+    let back = Rect::new(area.x, area.y + 1, width, area.height.saturating_sub(1));
+    let front = Rect::new(area.x + width, area.y + 1, area.width - width, area.height.saturating_sub(1));
+
+    let text = format!("{}: {} / {}", label, high.rank, low.rank);
+    frame.render_widget(Line::raw(text), Rect::new(area.x + 2, area.y, area.width.saturating_sub(2), 1));
+    render_hand(frame, &split.high, back, false);
+    render_hand(frame, &split.low, front, false);
+}
+
+fn divide(hand: &Hand, marks: &[bool]) -> Split {
+    let mut split = Split { high: Hand::new(), low: Hand::new() };
+    for (card, &mark) in hand.cards.iter().zip(marks) {
+        if mark { split.low.add(*card) } else { split.high.add(*card) }
+    }
+    split
+}
+
+fn sort(hand: &mut Hand) {
+    hand.cards.sort_by_key(|card| std::cmp::Reverse(card.value.score()));
+}
+
+pub fn splits(hand: &Hand) -> Vec<Split> {
+    let mut items = Vec::new();
+    for a in 0..hand.cards.len() {
+        for b in a + 1..hand.cards.len() {
+            let marks: Vec<bool> = (0..hand.cards.len()).map(|i| i == a || i == b).collect();
+            let split = divide(hand, &marks);
+            if split.legal() {
+                items.push(split);
+            }
+        }
+    }
+    items
+}
+
+pub fn house(hand: &Hand) -> Split {
+    let best = bug(&hand.cards);
+    let mut counts = [0u8; 16];
+    for card in &hand.cards {
+        let value = if card.is_joker() { Value::Ace } else { card.value };
+        counts[value.score() as usize] += 1;
+    }
+    let pairs: Vec<usize> = (2..15).rev().filter(|&value| counts[value] == 2).collect();
+    let quads = (2..15).find(|&value| counts[value] >= 4).unwrap_or(0);
+    let ace = counts[14] == 1;
+
+    let target = match best.rank {
+        Rank::Four if quads >= 11 || (quads >= 7 && !ace) => Rank::Pair,
+        Rank::Four => Rank::Four,
+        Rank::FullHouse => Rank::Pair,
+        Rank::TwoPair if pairs.len() >= 3 => Rank::Pair,
+        Rank::TwoPair if pairs[0] <= 10 && ace => Rank::TwoPair,
+        Rank::TwoPair => Rank::Pair,
+        Rank::Three if counts[14] == 3 => Rank::Pair,
+        rank => rank,
+    };
+
+    splits(hand)
+        .into_iter()
+        .filter(|split| split.scores().0.rank >= target)
+        .max_by_key(|split| {
+            let (high, low) = split.scores();
+            (low, high)
+        })
+        .or_else(|| splits(hand).into_iter().max_by_key(|split| split.scores().1))
+        .unwrap()
+}
+
+impl Play for PaiGow {
+    fn handle(&mut self, key: KeyEvent) {
+        match self.phase {
+            Phase::Bet => self.handle_bet(key),
+            Phase::Set => self.handle_set(key),
+            Phase::Result => self.handle_result(key),
+            _ => {}
+        }
+    }
+
+    fn render(&mut self, frame: &mut Frame, area: Rect) {
+        let inner = area.inner(Margin::new(1, 1));
+
+        let top = Rect::new(inner.x, inner.y, inner.width, 3);
+        let bottom = Rect::new(inner.x, inner.y + inner.height.saturating_sub(4), inner.width, 4);
+        let middle = Rect::new(inner.x, inner.y + 3, inner.width, inner.height.saturating_sub(7));
+
+        self.status(frame, top);
+        self.help(frame, bottom);
+
+        if self.phase != Phase::Bet {
+            self.scene(frame, middle);
+        }
+
+        render_pop(frame, &self.message, inner);
+    }
+
+    fn active(&self) -> bool {
+        self.phase != Phase::End
+    }
+
+    fn chips(&mut self) -> Option<&mut Wallet> {
+        Some(&mut self.money)
+    }
+
+    fn prime(&mut self, cards: Vec<Card>) -> Result<(), String> {
+        self.shoe.prime(cards)
+    }
+}
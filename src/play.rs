@@ -1,8 +1,16 @@
 use crossterm::event::KeyEvent;
 use ratatui::{layout::Rect, Frame};
 
+use crate::{card::Card, chips::Wallet};
+
 pub trait Play {
     fn handle(&mut self, key: KeyEvent);
     fn render(&mut self, frame: &mut Frame, area: Rect);
     fn active(&self) -> bool;
+    fn chips(&mut self) -> Option<&mut Wallet> {
+        None
+    }
+    fn prime(&mut self, _cards: Vec<Card>) -> Result<(), String> {
+        Err("no scenarios for this game".into())
+    }
 }
@@ -1,6 +1,11 @@
-use crate::card::{Card, Hand, Suit};
+use core::{
+    cmp::Ordering,
+    fmt::{Display, Formatter, Result},
+};
 
-#[derive(PartialEq, PartialOrd, Eq, Ord)]
+use crate::card::{Card, Hand, Shoe, Suit, Value, SUITS};
+
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Eq, Ord)]
 pub enum Rank {
     High,
     Pair,
@@ -11,41 +16,315 @@ pub enum Rank {
     FullHouse,
     Four,
     StraightFlush,
+    Five,
 }
 
-#[derive(PartialEq, PartialOrd, Eq, Ord)]
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Eq, Ord)]
 pub struct Score {
     pub rank: Rank,
     pub power: u32,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Low {
+    pub rank: Rank,
+    pub power: u32,
+}
+
+impl Display for Rank {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            Rank::High => write!(f, "High Card"),
+            Rank::Pair => write!(f, "Pair"),
+            Rank::TwoPair => write!(f, "Two Pair"),
+            Rank::Three => write!(f, "Three of a Kind"),
+            Rank::Straight => write!(f, "Straight"),
+            Rank::Flush => write!(f, "Flush"),
+            Rank::FullHouse => write!(f, "Full House"),
+            Rank::Four => write!(f, "Four of a Kind"),
+            Rank::StraightFlush => write!(f, "Straight Flush"),
+            Rank::Five => write!(f, "Five of a Kind"),
+        }
+    }
+}
+
+impl Score {
+    pub fn top(&self) -> u8 {
+        (self.power >> 16) as u8
+    }
+
+    pub fn strength(&self) -> f32 {
+        let top = (self.power >> 16) as f32 / 14.0;
+        match self.rank {
+            Rank::High => 0.05 + top * 0.15,
+            Rank::Pair => 0.25 + top * 0.2,
+            Rank::TwoPair => 0.55 + top * 0.1,
+            Rank::Three => 0.7,
+            Rank::Straight => 0.8,
+            Rank::Flush => 0.85,
+            Rank::FullHouse => 0.9,
+            Rank::Four => 0.97,
+            Rank::StraightFlush | Rank::Five => 1.0,
+        }
+    }
+}
+
+impl Ord for Low {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (other.rank, other.power).cmp(&(self.rank, self.power))
+    }
+}
+
+impl PartialOrd for Low {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Low {
+    pub fn top(&self) -> u8 {
+        (self.power >> 16) as u8
+    }
+
+    pub fn strength(&self) -> f32 {
+        if self.rank != Rank::High {
+            return 0.1;
+        }
+        match self.top() {
+            0..=5 => 1.0,
+            6 => 0.9,
+            7 => 0.75,
+            8 => 0.6,
+            9 => 0.4,
+            10 => 0.3,
+            _ => 0.15,
+        }
+    }
+}
+
+impl Display for Low {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        let mut names = Vec::new();
+        for shift in [16, 12, 8, 4, 0] {
+            let name = match (self.power >> shift) & 0xF {
+                0 => continue,
+                1 | 14 => "A".to_string(),
+                11 => "J".to_string(),
+                12 => "Q".to_string(),
+                13 => "K".to_string(),
+                value => value.to_string(),
+            };
+            names.push(name);
+        }
+        if self.rank == Rank::High {
+            write!(f, "{} Low", names.join("-"))
+        } else {
+            write!(f, "{}", self.rank)
+        }
+    }
+}
+
 pub fn evaluate(hole: &Hand, board: &Hand) -> Score {
     let mut pool = Vec::new();
     pool.extend(&hole.cards);
     pool.extend(&board.cards);
+    rate(&pool)
+}
+
+pub fn rate(pool: &[Card]) -> Score {
+    if pool.iter().any(|card| card.is_joker()) {
+        return wild(pool, &[]);
+    }
 
-    let multi = multiples(&pool);
+    let multi = multiples(pool);
     let mut best = multi;
 
-    if let Some(suity) = flush(&pool) {
-        if suity > best {
-            best = suity;
+    if let Some(suity) = flush(pool)
+        && suity > best
+    {
+        best = suity;
+    }
+
+    if let Some(run) = straight(pool)
+        && run > best
+    {
+        best = run;
+    }
+
+    best
+}
+
+pub fn wild(pool: &[Card], ranks: &[Value]) -> Score {
+    let (wilds, mut naturals): (Vec<Card>, Vec<Card>) = pool.iter().partition(|card| card.is_wild(ranks));
+    if wilds.is_empty() {
+        return rate(&naturals);
+    }
+
+    let mut suits: Vec<Suit> = Vec::new();
+    for card in &naturals {
+        if !suits.contains(&card.suit) {
+            suits.push(card.suit);
         }
     }
+    if let Some(other) = SUITS.into_iter().find(|suit| !suits.contains(suit)) {
+        suits.push(other);
+    }
+
+    let candidates: Vec<Card> = Shoe::new(1).cards.into_iter().filter(|card| suits.contains(&card.suit)).collect();
+    let mut best = None;
+    fill(&mut naturals, &candidates, wilds.len(), &mut best);
+    best.unwrap()
+}
+
+fn fill(pool: &mut Vec<Card>, candidates: &[Card], left: usize, best: &mut Option<Score>) {
+    if left == 0 {
+        *best = (*best).max(Some(rate(pool)));
+        return;
+    }
 
-    if let Some(run) = straight(&pool) {
-        if run > best {
-            best = run;
+    for (i, &card) in candidates.iter().enumerate() {
+        pool.push(card);
+        fill(pool, &candidates[i..], left - 1, best);
+        pool.pop();
+    }
+}
+
+pub fn bug(cards: &[Card]) -> Score {
+    let Some(index) = cards.iter().position(|card| card.is_joker()) else {
+        return rate(cards);
+    };
+
+    let mut trial = cards.to_vec();
+    Shoe::new(1)
+        .cards
+        .into_iter()
+        .filter_map(|card| {
+            trial[index] = card;
+            let score = rate(&trial);
+            if matches!(score.rank, Rank::Straight | Rank::Flush | Rank::StraightFlush) {
+                (!cards.contains(&card)).then_some(score)
+            } else {
+                (card.value == Value::Ace).then_some(score)
+            }
+        })
+        .max()
+        .unwrap()
+}
+
+pub fn three(cards: &[Card]) -> Score {
+    let base = rate(cards);
+    if base.rank != Rank::High || cards.len() != 3 {
+        return base;
+    }
+
+    let mut values: Vec<u8> = cards.iter().map(|card| card.value.score()).collect();
+    values.sort_by(|a, b| b.cmp(a));
+    let flush = cards.iter().all(|card| card.suit == cards[0].suit);
+    let high = if values == [14, 3, 2] {
+        Some(3)
+    } else if values[0] - values[2] == 2 {
+        Some(values[0])
+    } else {
+        None
+    };
+
+    match (high, flush) {
+        (Some(high), true) => Score { rank: Rank::StraightFlush, power: high as u32 },
+        (Some(high), false) => Score { rank: Rank::Straight, power: high as u32 },
+        (None, true) => Score { rank: Rank::Flush, power: base.power },
+        _ => base,
+    }
+}
+
+pub fn ace_five(cards: &[Card]) -> Low {
+    let cards: Vec<Card> = cards.iter().filter(|card| !card.is_joker()).copied().collect();
+    subsets(&cards, 5.min(cards.len()))
+        .iter()
+        .map(|five| {
+            let values: Vec<u8> = five.iter().map(low_score).collect();
+            let score = grouped(&values);
+            Low { rank: score.rank, power: score.power }
+        })
+        .max()
+        .unwrap()
+}
+
+pub fn eight(cards: &[Card]) -> Option<Low> {
+    let low = ace_five(cards);
+    (cards.len() >= 5 && low.rank == Rank::High && low.top() <= 8).then_some(low)
+}
+
+pub fn deuce_seven(cards: &[Card]) -> Low {
+    subsets(cards, 5.min(cards.len()))
+        .iter()
+        .map(|five| {
+            let mut score = rate(five);
+            if score.power == 5 && matches!(score.rank, Rank::Straight | Rank::StraightFlush) {
+                let values: Vec<u8> = five.iter().map(|card| card.value.score()).collect();
+                let high = grouped(&values);
+                score = if score.rank == Rank::Straight { high } else { Score { rank: Rank::Flush, power: high.power } };
+            }
+            Low { rank: score.rank, power: score.power }
+        })
+        .max()
+        .unwrap()
+}
+
+pub fn omaha_high(hole: &Hand, board: &Hand) -> Score {
+    let mut best = None;
+    for two in subsets(&hole.cards, 2) {
+        for three in subsets(&board.cards, 3) {
+            let pool: Vec<Card> = two.iter().chain(&three).copied().collect();
+            best = best.max(Some(rate(&pool)));
         }
     }
+    best.unwrap()
+}
 
+pub fn omaha_low(hole: &Hand, board: &Hand) -> Option<Low> {
+    let mut best = None;
+    for two in subsets(&hole.cards, 2) {
+        for three in subsets(&board.cards, 3) {
+            let pool: Vec<Card> = two.iter().chain(&three).copied().collect();
+            best = best.max(eight(&pool));
+        }
+    }
     best
 }
 
+pub fn subsets(cards: &[Card], size: usize) -> Vec<Vec<Card>> {
+    if size == 0 {
+        return vec![Vec::new()];
+    }
+    if cards.len() < size {
+        return Vec::new();
+    }
+
+    let mut items = Vec::new();
+    for mut rest in subsets(&cards[1..], size - 1) {
+        rest.insert(0, cards[0]);
+        items.push(rest);
+    }
+    items.extend(subsets(&cards[1..], size));
+    items
+}
+
+pub fn low_score(card: &Card) -> u8 {
+    match card.value.score() {
+        14 => 1,
+        score => score,
+    }
+}
+
 fn multiples(pool: &[Card]) -> Score {
-    let mut counts = [0u8; 15];
-    for card in pool {
-        counts[card.value.score() as usize] += 1;
+    let values: Vec<u8> = pool.iter().map(|card| card.value.score()).collect();
+    grouped(&values)
+}
+
+fn grouped(values: &[u8]) -> Score {
+    let mut counts = [0u8; 16];
+    for &value in values {
+        counts[value as usize] += 1;
     }
 
     let mut items = Vec::new();
@@ -56,7 +335,8 @@ fn multiples(pool: &[Card]) -> Score {
     }
     items.sort_by(|a, b| b.0.cmp(&a.0).then_with(|| b.1.cmp(&a.1)));
 
-    let rank = match items[0].0 {
+    let rank = match items.first().map_or(0, |item| item.0) {
+        5.. => Rank::Five,
         4 => Rank::Four,
         3 if items.len() > 1 && items[1].0 >= 2 => Rank::FullHouse,
         3 => Rank::Three,
@@ -87,9 +367,7 @@ fn multiples(pool: &[Card]) -> Score {
 }
 
 fn flush(pool: &[Card]) -> Option<Score> {
-    let suits = [Suit::Clubs, Suit::Diamonds, Suit::Hearts, Suit::Spades];
-
-    for suit in suits {
+    for suit in SUITS {
         let mut values = Vec::new();
         for card in pool {
             if card.suit == suit {
@@ -164,3 +442,40 @@ fn find_straight(values: &[u8]) -> Option<u8> {
 
     None
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bugged(text: &str) -> Score {
+        bug(&text.parse::<Hand>().unwrap().cards)
+    }
+
+    #[test]
+    fn bug_makes_five_aces() {
+        assert_eq!(bugged("As Ah Ad Ac Jk Ks 9d").rank, Rank::Five);
+        assert_eq!(bugged("As Ah Ad Ac Jk").rank, Rank::Five);
+    }
+
+    #[test]
+    fn bug_is_an_ace_without_aces() {
+        assert_eq!(bugged("Jk Kd"), bugged("Ac Kd"));
+        assert_eq!(bugged("Jk Kd 9c 5h 2s"), bugged("Ac Kd 9c 5h 2s"));
+        assert_eq!(bugged("Jk As").rank, Rank::Pair);
+    }
+
+    #[test]
+    fn bug_fills_straights_and_flushes() {
+        assert_eq!(bugged("Jk 9s 8d 7c 6h").rank, Rank::Straight);
+        assert_eq!(bugged("Jk As Ks 9s 4s").rank, Rank::Flush);
+    }
+
+    #[test]
+    fn omaha_plays_two_from_the_hand() {
+        let hand = |text: &str| text.parse::<Hand>().unwrap();
+        assert_eq!(omaha_high(&hand("As Kd 7c 2h"), &hand("Qs Js Ts 9s 3d")).rank, Rank::Straight);
+        assert_eq!(omaha_high(&hand("Ah 2c 3d 4c"), &hand("Ks Qs Js Ts 9s")).rank, Rank::High);
+        assert_eq!(omaha_low(&hand("Ah 2c Kd Kc"), &hand("3s 4d 8h Qs Jd")).unwrap().to_string(), "8-4-3-2-A Low");
+        assert!(omaha_low(&hand("Ah Kd Qc Jc"), &hand("2s 3d 4h 5s 9d")).is_none());
+    }
+}
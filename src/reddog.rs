@@ -0,0 +1,274 @@
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::{
+    layout::{Margin, Rect},
+    text::Line,
+    Frame,
+};
+
+use crate::{
+    card::{Card, Hand, Shoe},
+    chips::{net, Chips, Error, Wallet, HOUSE, STEP},
+    play::Play,
+    show::{render_card, render_help, render_pop, render_slot, render_status},
+};
+
+#[derive(PartialEq)]
+pub enum Phase {
+    Bet,
+    Raise,
+    Result,
+    End,
+}
+
+pub struct RedDog {
+    pub phase: Phase,
+    pub shoe: Shoe,
+    pub hand: Hand,
+    pub money: Wallet,
+    pub bank: Wallet,
+    pub bet: Chips,
+    pub stake: Chips,
+    pub message: String,
+}
+
+impl RedDog {
+    pub fn new() -> Self {
+        let mut shoe = Shoe::new(6);
+        shoe.shuffle();
+        Self {
+            phase: Phase::Bet,
+            shoe,
+            hand: Hand::new(),
+            money: Wallet::new(Chips::ZERO),
+            bank: Wallet::new(HOUSE),
+            bet: STEP,
+            stake: Chips::ZERO,
+            message: String::new(),
+        }
+    }
+
+    fn spread(&self) -> Option<u8> {
+        let low = self.hand.cards[0].value.score().min(self.hand.cards[1].value.score());
+        let high = self.hand.cards[0].value.score().max(self.hand.cards[1].value.score());
+        (high - low > 1).then(|| high - low - 1)
+    }
+
+    fn deal(&mut self) -> Result<(), Error> {
+        self.money.transfer(&mut self.bank, self.bet)?;
+        self.stake = self.bet;
+        self.hand = Hand::new();
+        self.message.clear();
+
+        if self.shoe.remaining() < 20 {
+            self.shoe.gather();
+            self.shoe.casino();
+            self.message = "Shuffled".into();
+        }
+
+        for _ in 0..2 {
+            self.hand.add(self.shoe.draw().unwrap());
+        }
+
+        let [first, second] = [self.hand.cards[0], self.hand.cards[1]];
+        if first.value == second.value {
+            let third = self.shoe.draw().unwrap();
+            self.hand.add(third);
+            if third.value == first.value {
+                self.settle(self.stake.times(12)?, "Three of a kind pays 11 to 1".into())
+            } else {
+                self.settle(self.stake, "Pair, push".into())
+            }
+        } else if self.spread().is_none() {
+            self.settle(self.stake, "Consecutive, push".into())
+        } else {
+            self.phase = Phase::Raise;
+            Ok(())
+        }
+    }
+
+    fn raise(&mut self) -> Result<(), Error> {
+        let stake = self.stake.plus(self.bet)?;
+        self.money.transfer(&mut self.bank, self.bet)?;
+        self.stake = stake;
+        self.reveal()
+    }
+
+    fn reveal(&mut self) -> Result<(), Error> {
+        let third = self.shoe.draw().unwrap();
+        self.hand.add(third);
+
+        let spread = self.spread().unwrap();
+        let low = self.hand.cards[0].value.score().min(self.hand.cards[1].value.score());
+        let high = self.hand.cards[0].value.score().max(self.hand.cards[1].value.score());
+
+        if (low + 1..high).contains(&third.value.score()) {
+            let pays = pays(spread);
+            self.settle(self.stake.times(pays + 1)?, format!("In between, pays {} to 1", pays))
+        } else {
+            self.settle(Chips::ZERO, "Outside the spread".into())
+        }
+    }
+
+    fn settle(&mut self, paid: Chips, text: String) -> Result<(), Error> {
+        self.bank.transfer(&mut self.money, paid)?;
+        self.message = format!("{} ({})", text, net(paid, self.stake));
+        if self.money.broke() {
+            self.message.push_str(" Bankrupt");
+        }
+        self.phase = Phase::Result;
+        Ok(())
+    }
+
+    fn handle_bet(&mut self, key: KeyEvent) {
+        match key.code {
+            KeyCode::Char('q') => self.phase = Phase::End,
+            KeyCode::Up | KeyCode::Char('w') => {
+                self.bet = self.bet.raise(STEP, self.money.balance());
+            }
+            KeyCode::Down | KeyCode::Char('s') => {
+                self.bet = self.bet.lower(STEP, STEP);
+            }
+            KeyCode::Enter => {
+                if let Err(error) = self.deal() {
+                    self.message = error.to_string();
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn handle_raise(&mut self, key: KeyEvent) {
+        match key.code {
+            KeyCode::Char('r') => {
+                if let Err(error) = self.raise() {
+                    self.message = error.to_string();
+                }
+            }
+            KeyCode::Char('c') | KeyCode::Enter => {
+                if let Err(error) = self.reveal() {
+                    self.message = error.to_string();
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn handle_result(&mut self, key: KeyEvent) {
+        match key.code {
+            KeyCode::Char('q') => self.phase = Phase::End,
+            KeyCode::Enter => {
+                if self.money.broke() {
+                    self.phase = Phase::End;
+                } else {
+                    self.bet = self.bet.min(self.money.balance());
+                    self.message.clear();
+                    self.phase = Phase::Bet;
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn status(&self, frame: &mut Frame, area: Rect) {
+        let spread = match (&self.phase, self.hand.cards.len()) {
+            (Phase::Bet, _) | (_, 0) => String::new(),
+            _ => match self.spread() {
+                Some(spread) => format!("Spread: {} pays {} to 1", spread, pays(spread)),
+                None => String::new(),
+            },
+        };
+        let lines = [
+            Line::raw(format!("Money: {}", self.money.balance())),
+            Line::raw(format!("Bet: {}", self.stake.max(self.bet))),
+            Line::raw(spread),
+        ];
+        render_status(frame, &lines, area);
+    }
+
+    fn help(&self, frame: &mut Frame, area: Rect) {
+        let lines = match self.phase {
+            Phase::Bet => vec![
+                Line::raw("[↑↓] Bet"),
+                Line::raw("[Enter] Deal"),
+                Line::raw("[Q] Quit"),
+            ],
+            Phase::Raise => vec![
+                Line::raw("[R] Raise"),
+                Line::raw("[C] Call"),
+            ],
+            Phase::Result => vec![
+                Line::raw("[Enter] Next"),
+                Line::raw("[Q] Quit"),
+            ],
+            _ => vec![Line::raw("")],
+        };
+        render_help(frame, &lines, area);
+    }
+
+    fn scene(&mut self, frame: &mut Frame, area: Rect) {
+        let y = area.y + area.height.saturating_sub(7) / 2;
+        let middle = area.x + area.width / 2;
+        let spots = [
+            Rect::new(middle.saturating_sub(17), y, 10, 7.min(area.height)),
+            Rect::new(middle + 7, y, 10, 7.min(area.height)),
+            Rect::new(middle.saturating_sub(5), y, 10, 7.min(area.height)),
+        ];
+
+        let cards: Vec<Option<&Card>> = (0..3).map(|i| self.hand.cards.get(i)).collect();
+        for (card, spot) in cards.into_iter().zip(spots) {
+            match card {
+                Some(card) => render_card(frame, card, spot),
+                None => render_slot(frame, "", spot),
+            }
+        }
+    }
+}
+
+pub fn pays(spread: u8) -> u64 {
+    match spread {
+        1 => 5,
+        2 => 4,
+        3 => 2,
+        _ => 1,
+    }
+}
+
+impl Play for RedDog {
+    fn handle(&mut self, key: KeyEvent) {
+        match self.phase {
+            Phase::Bet => self.handle_bet(key),
+            Phase::Raise => self.handle_raise(key),
+            Phase::Result => self.handle_result(key),
+            _ => {}
+        }
+    }
+
+    fn render(&mut self, frame: &mut Frame, area: Rect) {
+        let inner = area.inner(Margin::new(1, 1));
+
+        let top = Rect::new(inner.x, inner.y, inner.width, 3);
+        let bottom = Rect::new(inner.x, inner.y + inner.height.saturating_sub(4), inner.width, 4);
+        let middle = Rect::new(inner.x, inner.y + 3, inner.width, inner.height.saturating_sub(7));
+
+        self.status(frame, top);
+        self.help(frame, bottom);
+
+        if self.phase != Phase::Bet {
+            self.scene(frame, middle);
+        }
+
+        render_pop(frame, &self.message, inner);
+    }
+
+    fn active(&self) -> bool {
+        self.phase != Phase::End
+    }
+
+    fn chips(&mut self) -> Option<&mut Wallet> {
+        Some(&mut self.money)
+    }
+
+    fn prime(&mut self, cards: Vec<Card>) -> Result<(), String> {
+        self.shoe.prime(cards)
+    }
+}
@@ -0,0 +1,59 @@
+use crate::card::{Card, Hand};
+
+pub struct Scenario {
+    pub name: String,
+    pub game: String,
+    pub cards: Vec<Card>,
+}
+
+impl Scenario {
+    pub fn parse(text: &str) -> Result<Scenario, String> {
+        let mut name = None;
+        let mut game = None;
+        let mut cards = Vec::new();
+
+        for line in text.lines().map(str::trim) {
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let (key, rest) = line.split_once(' ').unwrap_or((line, ""));
+            match key {
+                "name" => name = Some(rest.trim().to_string()),
+                "game" => game = Some(rest.trim().to_string()),
+                "deal" => {
+                    let hand: Hand = rest.parse().map_err(|error| format!("{}", error))?;
+                    cards.extend(hand.cards);
+                }
+                _ => return Err(format!("unknown line: {}", line)),
+            }
+        }
+
+        Ok(Scenario {
+            name: name.ok_or("missing name")?,
+            game: game.ok_or("missing game")?,
+            cards,
+        })
+    }
+}
+
+pub fn load(dir: &str) -> Vec<Result<Scenario, String>> {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return Vec::new();
+    };
+
+    let mut paths: Vec<_> = entries
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| path.extension().is_some_and(|extension| extension == "txt"))
+        .collect();
+    paths.sort();
+
+    paths
+        .iter()
+        .map(|path| {
+            std::fs::read_to_string(path)
+                .map_err(|error| error.to_string())
+                .and_then(|text| Scenario::parse(&text))
+                .map_err(|error| format!("{}: {}", path.display(), error))
+        })
+        .collect()
+}
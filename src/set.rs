@@ -0,0 +1,175 @@
+use core::ops::{BitAnd, BitOr, Not, Sub};
+
+use crate::card::{Card, Hand, Suit, Value, SUITS, VALUES};
+
+const JOKER: u32 = 52;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct CardSet(pub u64);
+
+impl CardSet {
+    pub const EMPTY: CardSet = CardSet(0);
+    pub const DECK: CardSet = CardSet((1 << 52) - 1);
+
+    pub fn index(card: &Card) -> u32 {
+        match card.value {
+            Value::Joker => JOKER,
+            value => card.suit as u32 * 13 + (value.score() - 2) as u32,
+        }
+    }
+
+    pub fn card(index: u32) -> Card {
+        match index {
+            JOKER => Card::joker(),
+            _ => Card { suit: SUITS[(index / 13) as usize], value: VALUES[(index % 13) as usize] },
+        }
+    }
+
+    pub fn single(card: &Card) -> CardSet {
+        CardSet(1 << CardSet::index(card))
+    }
+
+    pub fn suit(suit: Suit) -> CardSet {
+        CardSet(0x1FFF << (suit as u32 * 13))
+    }
+
+    pub fn value(value: Value) -> CardSet {
+        match value {
+            Value::Joker => CardSet(1 << JOKER),
+            value => CardSet(0x0008_0040_0200_1000 >> (14 - value.score())),
+        }
+    }
+
+    pub fn insert(&mut self, card: &Card) {
+        self.0 |= CardSet::single(card).0;
+    }
+
+    pub fn remove(&mut self, card: &Card) -> bool {
+        let had = self.contains(card);
+        self.0 &= !CardSet::single(card).0;
+        had
+    }
+
+    pub fn contains(&self, card: &Card) -> bool {
+        self.0 & CardSet::single(card).0 != 0
+    }
+
+    pub fn union(self, other: CardSet) -> CardSet {
+        CardSet(self.0 | other.0)
+    }
+
+    pub fn intersection(self, other: CardSet) -> CardSet {
+        CardSet(self.0 & other.0)
+    }
+
+    pub fn difference(self, other: CardSet) -> CardSet {
+        CardSet(self.0 & !other.0)
+    }
+
+    pub fn count(&self) -> u32 {
+        self.0.count_ones()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    pub fn iter(&self) -> Cards {
+        Cards(self.0)
+    }
+}
+
+pub struct Cards(u64);
+
+impl Iterator for Cards {
+    type Item = Card;
+
+    fn next(&mut self) -> Option<Card> {
+        if self.0 == 0 {
+            return None;
+        }
+        let index = self.0.trailing_zeros();
+        self.0 &= self.0 - 1;
+        Some(CardSet::card(index))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let count = self.0.count_ones() as usize;
+        (count, Some(count))
+    }
+}
+
+impl IntoIterator for CardSet {
+    type Item = Card;
+    type IntoIter = Cards;
+
+    fn into_iter(self) -> Cards {
+        self.iter()
+    }
+}
+
+impl FromIterator<Card> for CardSet {
+    fn from_iter<I: IntoIterator<Item = Card>>(cards: I) -> Self {
+        let mut set = CardSet::EMPTY;
+        for card in cards {
+            set.insert(&card);
+        }
+        set
+    }
+}
+
+impl From<&[Card]> for CardSet {
+    fn from(cards: &[Card]) -> Self {
+        cards.iter().copied().collect()
+    }
+}
+
+impl From<&Hand> for CardSet {
+    fn from(hand: &Hand) -> Self {
+        CardSet::from(hand.cards.as_slice())
+    }
+}
+
+impl From<CardSet> for Vec<Card> {
+    fn from(set: CardSet) -> Self {
+        set.iter().collect()
+    }
+}
+
+impl From<CardSet> for Hand {
+    fn from(set: CardSet) -> Self {
+        Hand { cards: set.into() }
+    }
+}
+
+impl BitOr for CardSet {
+    type Output = CardSet;
+
+    fn bitor(self, other: CardSet) -> CardSet {
+        self.union(other)
+    }
+}
+
+impl BitAnd for CardSet {
+    type Output = CardSet;
+
+    fn bitand(self, other: CardSet) -> CardSet {
+        self.intersection(other)
+    }
+}
+
+impl Sub for CardSet {
+    type Output = CardSet;
+
+    fn sub(self, other: CardSet) -> CardSet {
+        self.difference(other)
+    }
+}
+
+impl Not for CardSet {
+    type Output = CardSet;
+
+    fn not(self) -> CardSet {
+        CardSet::DECK.difference(self)
+    }
+}
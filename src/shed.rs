@@ -0,0 +1,498 @@
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::{
+    layout::{Margin, Rect},
+    style::Color,
+    text::Line,
+    Frame,
+};
+
+use crate::{
+    card::{Card, Hand, Shoe, Suit, Value, SUITS},
+    play::Play,
+    show::{render_backs, render_card, render_focus, render_hand, render_help, render_pick, render_pop, render_slot, render_status},
+};
+
+#[derive(Clone, Copy)]
+pub struct Rules {
+    pub name: &'static str,
+    pub deal: usize,
+    pub wild: Value,
+    pub skip: Option<Value>,
+    pub reverse: Option<Value>,
+    pub draw: Option<Value>,
+    pub penalty: usize,
+    pub redraw: bool,
+}
+
+pub const EIGHTS: Rules = Rules {
+    name: "Crazy Eights",
+    deal: 5,
+    wild: Value::Eight,
+    skip: None,
+    reverse: None,
+    draw: None,
+    penalty: 0,
+    redraw: true,
+};
+
+pub const SWITCH: Rules = Rules {
+    name: "Switch",
+    deal: 7,
+    wild: Value::Ace,
+    skip: Some(Value::Eight),
+    reverse: Some(Value::King),
+    draw: Some(Value::Two),
+    penalty: 2,
+    redraw: false,
+};
+
+impl Rules {
+    pub fn special(&self, value: Value) -> bool {
+        [self.skip, self.reverse, self.draw].contains(&Some(value))
+    }
+
+    pub fn points(&self, card: &Card) -> u32 {
+        if card.value == self.wild {
+            50
+        } else if self.special(card.value) {
+            20
+        } else {
+            card.value.pips()
+        }
+    }
+}
+
+#[derive(PartialEq)]
+pub enum Phase {
+    Seats,
+    Play,
+    Suit,
+    Over,
+    End,
+}
+
+pub struct Shed {
+    pub phase: Phase,
+    pub rules: Rules,
+    pub bots: usize,
+    pub shoe: Shoe,
+    pub hands: Vec<Hand>,
+    pub pile: Vec<Card>,
+    pub suit: Suit,
+    pub turn: usize,
+    pub clockwise: bool,
+    pub drawn: bool,
+    pub scores: Vec<u32>,
+    pub last: Vec<String>,
+    pub cursor: usize,
+    pub pick: usize,
+    pub message: String,
+}
+
+impl Shed {
+    pub fn new(rules: Rules) -> Self {
+        Self {
+            phase: Phase::Seats,
+            rules,
+            bots: 3,
+            shoe: Shoe::new(1),
+            hands: Vec::new(),
+            pile: Vec::new(),
+            suit: Suit::Clubs,
+            turn: 0,
+            clockwise: true,
+            drawn: false,
+            scores: Vec::new(),
+            last: Vec::new(),
+            cursor: 0,
+            pick: 0,
+            message: String::new(),
+        }
+    }
+
+    fn seats(&self) -> usize {
+        self.bots + 1
+    }
+
+    fn name(&self, seat: usize) -> String {
+        if seat == 0 { "You".into() } else { format!("Bot {}", seat) }
+    }
+
+    fn deal(&mut self) {
+        let seats = self.seats();
+        if self.scores.len() != seats {
+            self.scores = vec![0; seats];
+        }
+
+        self.shoe.restock(Shoe::new(1));
+        self.shoe.shuffle();
+        self.hands = vec![Hand::new(); seats];
+        self.last = vec![String::new(); seats];
+        for _ in 0..self.rules.deal {
+            for hand in &mut self.hands {
+                hand.add(self.shoe.draw().unwrap());
+            }
+        }
+        sort(&mut self.hands[0]);
+
+        let mut top = self.shoe.draw().unwrap();
+        while top.value == self.rules.wild {
+            self.shoe.cards.insert(0, top);
+            top = self.shoe.draw().unwrap();
+        }
+        self.pile = vec![top];
+        self.suit = top.suit;
+        self.turn = 0;
+        self.clockwise = true;
+        self.drawn = false;
+        self.cursor = 0;
+        self.message.clear();
+        self.phase = Phase::Play;
+    }
+
+    fn next(&self, from: usize) -> usize {
+        let seats = self.seats();
+        if self.clockwise { (from + 1) % seats } else { (from + seats - 1) % seats }
+    }
+
+    fn playable(&self, card: &Card) -> bool {
+        let top = self.pile.last().unwrap();
+        card.value == self.rules.wild || card.suit == self.suit || card.value == top.value
+    }
+
+    fn legal(&self, seat: usize) -> Vec<usize> {
+        (0..self.hands[seat].cards.len()).filter(|&i| self.playable(&self.hands[seat].cards[i])).collect()
+    }
+
+    fn take(&mut self, seat: usize) -> Option<Card> {
+        if self.shoe.remaining() == 0 && self.pile.len() > 1 {
+            let top = self.pile.pop().unwrap();
+            self.shoe.cards = std::mem::replace(&mut self.pile, vec![top]);
+            self.shoe.shuffle();
+        }
+        let card = self.shoe.draw()?;
+        self.hands[seat].add(card);
+        Some(card)
+    }
+
+    fn play(&mut self, seat: usize, index: usize) {
+        let card = self.hands[seat].cards.remove(index);
+        self.pile.push(card);
+        self.suit = card.suit;
+        self.last[seat] = format!("Played {}{}", card.value, card.suit);
+
+        if self.hands[seat].cards.is_empty() {
+            self.finish(seat);
+            return;
+        }
+
+        if card.value == self.rules.wild {
+            if seat == 0 {
+                self.pick = 0;
+                self.phase = Phase::Suit;
+                return;
+            }
+            self.suit = favorite(&self.hands[seat], self.rules.wild).unwrap_or(card.suit);
+            self.last[seat] = format!("Played {}{}, called {}", card.value, card.suit, self.suit);
+        }
+
+        self.advance(card);
+    }
+
+    fn advance(&mut self, card: Card) {
+        let mut skip = false;
+
+        if Some(card.value) == self.rules.reverse {
+            self.clockwise = !self.clockwise;
+            skip = self.seats() == 2;
+        }
+        if Some(card.value) == self.rules.skip {
+            skip = true;
+        }
+        if Some(card.value) == self.rules.draw {
+            let victim = self.next(self.turn);
+            let count = (0..self.rules.penalty).filter_map(|_| self.take(victim)).count();
+            self.last[victim] = format!("Drew {} and lost a turn", count);
+            if victim == 0 {
+                sort(&mut self.hands[0]);
+            }
+            skip = true;
+        }
+
+        self.turn = self.next(self.turn);
+        if skip {
+            self.turn = self.next(self.turn);
+        }
+        self.drawn = false;
+    }
+
+    fn pass(&mut self) {
+        self.turn = self.next(self.turn);
+        self.drawn = false;
+    }
+
+    fn finish(&mut self, seat: usize) {
+        let points: u32 = self.hands.iter().flat_map(|hand| hand.cards.iter()).map(|card| self.rules.points(card)).sum();
+        self.scores[seat] += points;
+        self.message = format!("{} went out for {}", self.name(seat), points);
+        self.phase = Phase::Over;
+    }
+
+    fn flow(&mut self) {
+        while self.phase == Phase::Play && self.turn != 0 {
+            self.bot(self.turn);
+        }
+        self.cursor = self.cursor.min(self.hands[0].cards.len().saturating_sub(1));
+    }
+
+    fn bot(&mut self, seat: usize) {
+        let mut drawn = 0;
+        loop {
+            let legal = self.legal(seat);
+            if let Some(index) = choose(&self.hands[seat], &legal, &self.rules) {
+                self.play(seat, index);
+                if drawn > 0 {
+                    self.last[seat] = format!("Drew {} · {}", drawn, self.last[seat]);
+                }
+                return;
+            }
+            if (drawn > 0 && !self.rules.redraw) || self.take(seat).is_none() {
+                self.last[seat] = format!("Drew {} and passed", drawn);
+                self.pass();
+                return;
+            }
+            drawn += 1;
+        }
+    }
+
+    fn handle_seats(&mut self, key: KeyEvent) {
+        match key.code {
+            KeyCode::Char('q') => self.phase = Phase::End,
+            KeyCode::Up | KeyCode::Char('w') => self.bots = (self.bots + 1).min(4),
+            KeyCode::Down | KeyCode::Char('s') => self.bots = (self.bots - 1).max(2),
+            KeyCode::Enter => self.deal(),
+            _ => {}
+        }
+    }
+
+    fn handle_play(&mut self, key: KeyEvent) {
+        let last = self.hands[0].cards.len().saturating_sub(1);
+        match key.code {
+            KeyCode::Char('q') => self.phase = Phase::End,
+            KeyCode::Left | KeyCode::Char('a') => self.cursor = self.cursor.saturating_sub(1),
+            KeyCode::Right | KeyCode::Char('d') => self.cursor = (self.cursor + 1).min(last),
+            KeyCode::Enter | KeyCode::Char(' ') => {
+                if !self.playable(&self.hands[0].cards[self.cursor]) {
+                    self.message = format!("Match {} or {}", self.suit, self.pile.last().unwrap().value);
+                    return;
+                }
+                self.message.clear();
+                self.last[0].clear();
+                self.play(0, self.cursor);
+                self.flow();
+            }
+            KeyCode::Char('t') => {
+                if self.drawn && !self.rules.redraw {
+                    self.message = "Already drew this turn".into();
+                    return;
+                }
+                self.message.clear();
+                match self.take(0) {
+                    Some(card) => {
+                        sort(&mut self.hands[0]);
+                        self.cursor = self.hands[0].cards.iter().position(|other| *other == card).unwrap();
+                        self.drawn = true;
+                    }
+                    None => {
+                        self.message = "Nothing left to draw".into();
+                        self.pass();
+                        self.flow();
+                    }
+                }
+            }
+            KeyCode::Char('p') if self.drawn && !self.rules.redraw => {
+                self.message.clear();
+                self.pass();
+                self.flow();
+            }
+            _ => {}
+        }
+    }
+
+    fn handle_suit(&mut self, key: KeyEvent) {
+        match key.code {
+            KeyCode::Char('q') => self.phase = Phase::End,
+            KeyCode::Left | KeyCode::Char('a') => self.pick = (self.pick + 3) % 4,
+            KeyCode::Right | KeyCode::Char('d') => self.pick = (self.pick + 1) % 4,
+            KeyCode::Enter => {
+                let card = *self.pile.last().unwrap();
+                self.suit = SUITS[self.pick];
+                self.phase = Phase::Play;
+                self.advance(card);
+                self.flow();
+            }
+            _ => {}
+        }
+    }
+
+    fn handle_over(&mut self, key: KeyEvent) {
+        match key.code {
+            KeyCode::Char('q') => self.phase = Phase::End,
+            KeyCode::Enter => self.deal(),
+            _ => {}
+        }
+    }
+
+    fn status(&self, frame: &mut Frame, area: Rect) {
+        let scores: Vec<String> = (0..self.scores.len()).map(|seat| format!("{}: {}", self.name(seat), self.scores[seat])).collect();
+        let lines = [
+            Line::raw(format!("{}  Bots: {}", self.rules.name, self.bots)),
+            Line::raw(format!("Suit: {}  Play: {}", self.suit, if self.clockwise { "↻" } else { "↺" })),
+            Line::raw(scores.join("  ")),
+        ];
+        render_status(frame, &lines, area);
+    }
+
+    fn help(&self, frame: &mut Frame, area: Rect) {
+        let lines = match self.phase {
+            Phase::Seats => vec![
+                Line::raw("[↑↓] Bots"),
+                Line::raw("[Enter] Deal"),
+                Line::raw("[Q] Quit"),
+            ],
+            Phase::Play if self.drawn && !self.rules.redraw => vec![
+                Line::raw("[←→] Move"),
+                Line::raw("[Enter] Play"),
+                Line::raw("[P] Pass"),
+                Line::raw("[Q] Quit"),
+            ],
+            Phase::Play => vec![
+                Line::raw("[←→] Move"),
+                Line::raw("[Enter] Play"),
+                Line::raw("[T] Take from stock"),
+                Line::raw("[Q] Quit"),
+            ],
+            Phase::Suit => vec![
+                Line::raw("[←→] Suit"),
+                Line::raw("[Enter] Call"),
+                Line::raw("[Q] Quit"),
+            ],
+            Phase::Over => vec![
+                Line::raw("[Enter] Next round"),
+                Line::raw("[Q] Quit"),
+            ],
+            _ => vec![Line::raw("")],
+        };
+        render_help(frame, &lines, area);
+    }
+
+    fn scene(&mut self, frame: &mut Frame, area: Rect) {
+        if self.phase == Phase::Seats {
+            return;
+        }
+
+        let chunk = area.height / 3;
+        let top = Rect::new(area.x, area.y, area.width, chunk);
+        let table = Rect::new(area.x, area.y + chunk, area.width, chunk);
+        let mine = Rect::new(area.x, area.y + chunk * 2, area.width, chunk);
+
+        let width = top.width / self.bots as u16;
+        for seat in 1..self.seats() {
+            let spot = Rect::new(top.x + width * (seat as u16 - 1), top.y, width, top.height);
+            let label = format!("{} ({})", self.name(seat), self.hands[seat].cards.len());
+            frame.render_widget(Line::raw(label).centered(), Rect::new(spot.x, spot.y, spot.width, 1));
+            frame.render_widget(Line::raw(self.last[seat].as_str()).centered(), Rect::new(spot.x, spot.y + 1, spot.width, 1));
+            let cards = Rect::new(spot.x, spot.y + 2, spot.width, spot.height.saturating_sub(2));
+            if self.phase == Phase::Over {
+                render_hand(frame, &self.hands[seat], cards, false);
+            } else {
+                render_backs(frame, self.hands[seat].cards.len(), cards);
+            }
+        }
+
+        let middle = table.x + table.width / 2;
+        let y = table.y + table.height.saturating_sub(7) / 2;
+        let stock = Rect::new(middle.saturating_sub(12), y, 10, 7.min(table.height));
+        let pile = Rect::new(middle + 2, y, 10, 7.min(table.height));
+
+        if self.shoe.remaining() > 0 {
+            render_backs(frame, 1, stock);
+        } else {
+            render_slot(frame, "", stock);
+        }
+        render_card(frame, self.pile.last().unwrap(), pile);
+
+        if self.phase == Phase::Suit {
+            let x = middle + 14;
+            let spot = Rect::new(x, y, (table.x + table.width).saturating_sub(x).min(46), 7.min(table.height));
+            let calls = Hand { cards: SUITS.iter().map(|&suit| Card { suit, value: self.rules.wild }).collect() };
+            render_pick(frame, &calls, spot, self.pick, &[]);
+        } else if self.pile.last().unwrap().suit != self.suit {
+            let spot = Rect::new(middle + 14, y + 3, 14, 1);
+            frame.render_widget(Line::raw(format!("Called {}", self.suit)), spot);
+        }
+
+        let legal = self.legal(0);
+        let marks: Vec<bool> = (0..self.hands[0].cards.len()).map(|i| self.phase == Phase::Play && legal.contains(&i)).collect();
+        let cursor = if self.phase == Phase::Play { self.cursor } else { usize::MAX };
+        render_pick(frame, &self.hands[0], mine, cursor, &marks);
+        if self.phase == Phase::Play && self.turn == 0 && legal.is_empty() {
+            render_focus(frame, stock, Color::Yellow);
+        }
+    }
+}
+
+fn choose(hand: &Hand, legal: &[usize], rules: &Rules) -> Option<usize> {
+    legal.iter().copied().min_by_key(|&i| {
+        let card = &hand.cards[i];
+        (card.value == rules.wild, std::cmp::Reverse(rules.points(card)))
+    })
+}
+
+fn favorite(hand: &Hand, wild: Value) -> Option<Suit> {
+    SUITS
+        .iter()
+        .copied()
+        .map(|suit| (suit, hand.cards.iter().filter(|card| card.suit == suit && card.value != wild).count()))
+        .filter(|&(_, count)| count > 0)
+        .max_by_key(|&(_, count)| count)
+        .map(|(suit, _)| suit)
+}
+
+fn sort(hand: &mut Hand) {
+    hand.cards.sort_by_key(|card| (SUITS.iter().position(|&suit| suit == card.suit), card.value.order()));
+}
+
+impl Play for Shed {
+    fn handle(&mut self, key: KeyEvent) {
+        match self.phase {
+            Phase::Seats => self.handle_seats(key),
+            Phase::Play => self.handle_play(key),
+            Phase::Suit => self.handle_suit(key),
+            Phase::Over => self.handle_over(key),
+            _ => {}
+        }
+    }
+
+    fn render(&mut self, frame: &mut Frame, area: Rect) {
+        let inner = area.inner(Margin::new(1, 1));
+
+        let top = Rect::new(inner.x, inner.y, inner.width, 3);
+        let bottom = Rect::new(inner.x, inner.y + inner.height.saturating_sub(4), inner.width, 4);
+        let middle = Rect::new(inner.x, inner.y + 3, inner.width, inner.height.saturating_sub(7));
+
+        self.status(frame, top);
+        self.help(frame, bottom);
+        self.scene(frame, middle);
+
+        render_pop(frame, &self.message, inner);
+    }
+
+    fn active(&self) -> bool {
+        self.phase != Phase::End
+    }
+
+    fn prime(&mut self, cards: Vec<Card>) -> Result<(), String> {
+        self.shoe.prime(cards)
+    }
+}
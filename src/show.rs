@@ -1,19 +1,22 @@
-use crate::card::{Card, Hand, Suit};
+use core::fmt::Display;
+
+use crate::card::{Card, Hand, Shoe, Suit, SUITS, VALUES};
 use ratatui::{
-    layout::Rect,
+    layout::{Alignment, Rect},
     prelude::{Color, Line, Span, Style},
     widgets::{Block, BorderType, Borders, Clear, Paragraph},
     Frame,
 };
 
 pub fn render_card(frame: &mut Frame, card: &Card, area: Rect) {
-    let style = Style::default().fg(color(card.suit));
+    let tint = if card.is_joker() { Color::Magenta } else { color(card.suit) };
+    let style = Style::default().fg(tint);
     let width = area.width.saturating_sub(2) as usize;
     let height = area.height.saturating_sub(2) as usize;
     let mut lines = Vec::new();
 
     let val = format!("{}", card.value);
-    let suit = format!("{}", card.suit);
+    let suit = if card.is_joker() { "JOKER".to_string() } else { format!("{}", card.suit) };
 
     for row in 0..height {
         if row == 0 {
@@ -22,7 +25,7 @@ pub fn render_card(frame: &mut Frame, card: &Card, area: Rect) {
             let pad = " ".repeat(width.saturating_sub(suit.len()) / 2);
             lines.push(Line::from(Span::styled(format!("{}{}", pad, suit), style)));
         } else if row == height.saturating_sub(1) && height > 3 {
-            let pad = " ".repeat(width.saturating_sub(val.len() + 1));
+            let pad = " ".repeat(width.saturating_sub(val.chars().count() + 1));
             lines.push(Line::from(Span::styled(format!("{}{} ", pad, val), style)));
         } else {
             lines.push(Line::from(Span::raw("")));
@@ -70,9 +73,127 @@ pub fn render_hidden(frame: &mut Frame, area: Rect) {
 }
 
 pub fn render_hand(frame: &mut Frame, hand: &Hand, area: Rect, hide: bool) {
-    let count = hand.cards.len() as u16;
+    render_mask(frame, hand, area, &[false, hide]);
+}
+
+pub fn render_mask(frame: &mut Frame, hand: &Hand, area: Rect, hidden: &[bool]) {
+    for (i, (card, item)) in hand.cards.iter().zip(slots(hand.cards.len(), area)).enumerate() {
+        if hidden.get(i).copied().unwrap_or(false) {
+            render_hidden(frame, item);
+        } else {
+            render_card(frame, card, item);
+        }
+    }
+}
+
+pub fn render_backs(frame: &mut Frame, count: usize, area: Rect) {
+    for item in slots(count, area) {
+        render_hidden(frame, item);
+    }
+}
+
+pub fn render_pick(frame: &mut Frame, hand: &Hand, area: Rect, cursor: usize, marks: &[bool]) {
+    for (i, (card, mut item)) in hand.cards.iter().zip(slots(hand.cards.len(), area)).enumerate() {
+        if marks.get(i).copied().unwrap_or(false) {
+            item.y = item.y.saturating_sub(1).max(area.y);
+        }
+
+        render_card(frame, card, item);
+
+        if i == cursor {
+            render_focus(frame, item, Color::Yellow);
+        }
+    }
+}
+
+pub fn render_focus(frame: &mut Frame, area: Rect, tint: Color) {
+    let edge = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .border_style(Style::default().fg(tint));
+    frame.render_widget(edge, area);
+}
+
+pub fn render_slot(frame: &mut Frame, label: &str, area: Rect) {
+    let item = Paragraph::new(Line::raw(label))
+        .alignment(Alignment::Center)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .style(Style::default().fg(Color::DarkGray)),
+        );
+    frame.render_widget(Clear, area);
+    frame.render_widget(item, area);
+}
+
+pub fn render_stack(frame: &mut Frame, cards: &[Card], hidden: usize, area: Rect) -> Rect {
+    let height = 7.min(area.height);
+    let count = cards.len() as u16;
+    let below = area.height.saturating_sub(height);
+    let step = if count > 1 && hidden as u16 + (count - 1 - hidden as u16) * 2 > below { 1 } else { 2 };
+
+    let mut y = area.y;
+    let mut last = Rect::new(area.x, area.y, area.width, height);
+
+    for (i, card) in cards.iter().enumerate() {
+        let top = i + 1 == cards.len();
+        let lift = if i < hidden { 1 } else { step };
+        let rows = if top { height } else { lift };
+        let item = Rect::new(area.x, y.min(area.bottom().saturating_sub(rows)), area.width, rows);
+
+        if top {
+            if i < hidden {
+                render_hidden(frame, item);
+            } else {
+                render_card(frame, card, item);
+            }
+            last = item;
+        } else if i < hidden {
+            let strip = Block::default()
+                .borders(Borders::TOP | Borders::LEFT | Borders::RIGHT)
+                .border_type(BorderType::Rounded)
+                .style(Style::default().fg(Color::DarkGray));
+            frame.render_widget(Clear, item);
+            frame.render_widget(strip, item);
+        } else {
+            let title = Span::styled(format!(" {}{} ", card.value, card.suit), Style::default().fg(color(card.suit)));
+            let peek = Block::default()
+                .borders(Borders::TOP | Borders::LEFT | Borders::RIGHT)
+                .border_type(BorderType::Rounded)
+                .title(Line::from(title))
+                .style(Style::default().fg(Color::White));
+            frame.render_widget(Clear, item);
+            frame.render_widget(peek, item);
+        }
+
+        y += lift;
+    }
+
+    last
+}
+
+pub fn render_grid(frame: &mut Frame, rows: &[Vec<Card>], sizes: &[usize], area: Rect, cursor: Option<usize>) {
+    let height = (area.height / sizes.len().max(1) as u16).min(7);
+    for (r, (row, &size)) in rows.iter().zip(sizes).enumerate() {
+        let band = Rect::new(area.x, area.y + r as u16 * height, area.width, height);
+        for (i, item) in slots(size, band).into_iter().enumerate() {
+            match row.get(i) {
+                Some(card) => render_card(frame, card, item),
+                None => render_slot(frame, "", item),
+            }
+            if cursor == Some(r) && i == row.len().min(size - 1) {
+                render_focus(frame, item, if row.len() < size { Color::Yellow } else { Color::Red });
+            }
+        }
+    }
+}
+
+fn slots(count: usize, area: Rect) -> Vec<Rect> {
+    let count = count as u16;
+    let mut items = Vec::new();
     if count == 0 {
-        return;
+        return items;
     }
 
     let gap = 1;
@@ -83,28 +204,112 @@ pub fn render_hand(frame: &mut Frame, hand: &Hand, area: Rect, hide: bool) {
     let start = area.x + area.width.saturating_sub(total) / 2;
     let y = area.y + area.height.saturating_sub(height) / 2;
 
-    for (i, card) in hand.cards.iter().enumerate() {
-        let offset = i as u16 * (width + gap);
+    for i in 0..count {
+        let offset = i * (width + gap);
         if start + offset + width > area.x + area.width {
             break;
         }
-
-        let item = Rect::new(start + offset, y, width, height);
-
-        if hide && i == 1 {
-            render_hidden(frame, item);
-        } else {
-            render_card(frame, card, item);
-        }
+        items.push(Rect::new(start + offset, y, width, height));
     }
+
+    items
 }
 
-pub fn render_sum(frame: &mut Frame, label: &str, sum: usize, area: Rect) {
+pub fn render_sum(frame: &mut Frame, label: &str, sum: impl Display, area: Rect) {
     let text = Line::raw(format!("{}: {}", label, sum)).style(Style::default().fg(Color::White));
     frame.render_widget(Clear, area);
     frame.render_widget(text, area);
 }
 
+pub fn render_status(frame: &mut Frame, lines: &[Line], area: Rect) {
+    let width = area.width.saturating_sub(2);
+    frame.render_widget(Clear, area);
+    for (i, line) in lines.iter().enumerate() {
+        frame.render_widget(line.clone(), Rect::new(area.x + 2, area.y + i as u16, width, 1));
+    }
+}
+
+pub fn render_help(frame: &mut Frame, lines: &[Line], area: Rect) {
+    let y = area.y + area.height.saturating_sub(lines.len() as u16);
+    let width = area.width.saturating_sub(4);
+    frame.render_widget(Clear, area);
+    for (i, line) in lines.iter().enumerate() {
+        let item = Rect::new(area.x + 2, y + i as u16, width, 1);
+        frame.render_widget(line.clone(), item);
+    }
+}
+
+pub fn render_shoe(frame: &mut Frame, shoe: &Shoe, area: Rect) {
+    let values = shoe.values();
+    let top = VALUES
+        .iter()
+        .zip(values)
+        .map(|(value, count)| count + shoe.dealt.iter().filter(|card| card.value == *value).count())
+        .max()
+        .unwrap_or(0)
+        .max(1);
+    let width = 16;
+
+    let mut lines: Vec<Line> = VALUES
+        .iter()
+        .zip(values)
+        .map(|(value, count)| {
+            let bar = "█".repeat((count * width).div_ceil(top));
+            Line::raw(format!("{:>2} {:<16} {:>3}", value.code(), bar, count))
+        })
+        .collect();
+
+    let suits: Vec<String> = SUITS.iter().zip(shoe.suits()).map(|(suit, count)| format!("{}{}", suit, count)).collect();
+    lines.push(Line::raw(""));
+    lines.push(Line::raw(format!(" {}", suits.join(" "))));
+    lines.push(Line::raw(format!(" Decks left: {:.1}", shoe.decks())));
+    lines.push(Line::raw(format!(" Dealt: {}  Left: {}", shoe.dealt.len(), shoe.remaining())));
+
+    let height = (lines.len() as u16 + 2).min(area.height);
+    let spot = Rect::new(area.x + area.width.saturating_sub(28), area.y, 28.min(area.width), height);
+    let item = Paragraph::new(lines).block(
+        Block::default()
+            .title(" Shoe ")
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .style(Style::default().fg(Color::White)),
+    );
+
+    frame.render_widget(Clear, spot);
+    frame.render_widget(item, spot);
+}
+
+pub fn render_pop(frame: &mut Frame, message: &str, area: Rect) {
+    if message.is_empty() {
+        return;
+    }
+    let width = message.chars().count() as u16 + 6;
+    let x = area.x + area.width.saturating_sub(width) / 2;
+    let y = area.y + area.height / 2;
+    let spot = Rect::new(x, y, width, 1);
+
+    let text = Line::raw(message).style(Style::default().fg(Color::Black).bg(Color::White));
+    let item = Paragraph::new(text).alignment(Alignment::Center);
+
+    frame.render_widget(Clear, spot);
+    frame.render_widget(item, spot);
+}
+
+pub fn compass(area: Rect, side: u16, edge: u16) -> ([Rect; 4], Rect) {
+    let side = side.min(area.width / 3);
+    let edge = edge.min(area.height / 3);
+    let width = area.width.saturating_sub(side * 2);
+    let height = area.height.saturating_sub(edge * 2);
+
+    let south = Rect::new(area.x + side, area.y + area.height - edge, width, edge);
+    let west = Rect::new(area.x, area.y + edge, side, height);
+    let north = Rect::new(area.x + side, area.y, width, edge);
+    let east = Rect::new(area.x + area.width - side, area.y + edge, side, height);
+    let center = Rect::new(area.x + side, area.y + edge, width, height);
+
+    ([south, west, north, east], center)
+}
+
 pub fn color(suit: Suit) -> Color {
     match suit {
         Suit::Spades | Suit::Clubs => Color::White,
@@ -0,0 +1,86 @@
+use rand::{Rng, RngExt};
+
+pub fn riffle<T: Copy, R: Rng + ?Sized>(cards: &mut Vec<T>, rng: &mut R) {
+    let count = cards.len();
+    let cut = (0..count)
+        .step_by(64)
+        .map(|start| (rng.random::<u64>() >> (64 - (count - start).min(64))).count_ones() as usize)
+        .sum::<usize>();
+    let source = std::mem::take(cards);
+    let (mut left, mut right) = (0, cut);
+
+    while left < cut || right < count {
+        let (a, b) = (cut - left, count - right);
+        if rng.random_range(0..a + b) < a {
+            cards.push(source[left]);
+            left += 1;
+        } else {
+            cards.push(source[right]);
+            right += 1;
+        }
+    }
+}
+
+pub fn strip<T: Copy, R: Rng + ?Sized>(cards: &mut Vec<T>, rng: &mut R) {
+    let mut packets = Vec::new();
+    let mut rest = cards.as_slice();
+    while !rest.is_empty() {
+        let size = rng.random_range(3..=10).min(rest.len());
+        packets.push(&rest[..size]);
+        rest = &rest[size..];
+    }
+    *cards = packets.into_iter().rev().flatten().copied().collect();
+}
+
+pub fn boxed<T: Copy, R: Rng + ?Sized>(cards: &mut Vec<T>, rng: &mut R) {
+    let count = cards.len();
+    let slack = (count / 16).max(1);
+    let mut cuts = vec![0];
+    for quarter in 1..4 {
+        let cut = (count * quarter / 4 + rng.random_range(0..slack)).saturating_sub(slack / 2);
+        cuts.push(cut.clamp(*cuts.last().unwrap(), count));
+    }
+    cuts.push(count);
+    *cards = cuts.windows(2).rev().flat_map(|pair| cards[pair[0]..pair[1]].to_vec()).collect();
+}
+
+pub fn rising(order: &[usize]) -> usize {
+    let mut position = vec![0; order.len()];
+    for (i, &card) in order.iter().enumerate() {
+        position[card] = i;
+    }
+    1 + position.windows(2).filter(|pair| pair[1] < pair[0]).count()
+}
+
+pub fn eulerian(count: usize) -> Vec<f64> {
+    let mut odds = vec![1.0];
+    for n in 2..=count {
+        let mut next = vec![0.0; n];
+        for (k, item) in next.iter_mut().enumerate() {
+            let stay = odds.get(k).map_or(0.0, |p| (k + 1) as f64 * p);
+            let rise = k.checked_sub(1).and_then(|below| odds.get(below)).map_or(0.0, |p| (n - k) as f64 * p);
+            *item = (stay + rise) / n as f64;
+        }
+        odds = next;
+    }
+    odds
+}
+
+pub fn distance<R: Rng + ?Sized>(riffles: usize, count: usize, trials: usize, rng: &mut R) -> (f64, f64) {
+    let uniform = eulerian(count);
+    let mut seen = vec![0usize; count + 1];
+    let mut total = 0;
+
+    for _ in 0..trials {
+        let mut order: Vec<usize> = (0..count).collect();
+        for _ in 0..riffles {
+            riffle(&mut order, rng);
+        }
+        let runs = rising(&order);
+        seen[runs] += 1;
+        total += runs;
+    }
+
+    let gap: f64 = (1..=count).map(|runs| (seen[runs] as f64 / trials as f64 - uniform[runs - 1]).abs()).sum();
+    (total as f64 / trials as f64, gap / 2.0)
+}
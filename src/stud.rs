@@ -0,0 +1,360 @@
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::{
+    layout::{Margin, Rect},
+    text::Line,
+    Frame,
+};
+
+use crate::{
+    card::{Card, Hand, Shoe, Suit, SUITS},
+    chips::{Chips, Error, Wallet},
+    play::Play,
+    poker::{ace_five, eight, evaluate, low_score},
+    show::{render_hand, render_help, render_mask, render_pop, render_status},
+    table::{Round, Seat, Step, Table},
+};
+
+#[derive(PartialEq, Clone, Copy)]
+pub enum Variant {
+    High,
+    Razz,
+    Eight,
+}
+
+#[derive(PartialEq)]
+pub enum Phase {
+    Ante,
+    Third,
+    Fourth,
+    Fifth,
+    Sixth,
+    Seventh,
+    Result,
+    End,
+}
+
+pub struct Stud {
+    pub variant: Variant,
+    pub phase: Phase,
+    pub shoe: Shoe,
+    pub table: Table,
+    pub down: Vec<Vec<bool>>,
+    pub message: String,
+}
+
+impl Stud {
+    pub fn new(variant: Variant) -> Self {
+        let seats = vec![
+            Seat::new("You"),
+            Seat::new("Bot 1"),
+            Seat::new("Bot 2"),
+            Seat::new("Bot 3"),
+        ];
+        Self {
+            variant,
+            phase: Phase::Ante,
+            shoe: Shoe::new(1),
+            down: vec![Vec::new(); seats.len()],
+            table: Table::new(seats),
+            message: String::new(),
+        }
+    }
+
+    fn deal(&mut self) -> Result<(), Error> {
+        self.message.clear();
+        self.table.reset();
+        self.shoe.restock(Shoe::new(1));
+        self.shoe.shuffle();
+
+        let bet = self.table.bet;
+        self.table.refill(bet.times(4)?)?;
+        self.table.ante(bet.split(5).0.max(Chips::dollars(1)))?;
+
+        for down in &mut self.down {
+            down.clear();
+        }
+        for hidden in [true, true, false] {
+            self.give(hidden);
+        }
+
+        let low = self.bringer();
+        self.table.open(low, bet);
+        self.table.bring(low, bet.split(2).0)?;
+        self.phase = Phase::Third;
+        self.flow()
+    }
+
+    fn give(&mut self, hidden: bool) {
+        for index in 0..self.table.seats.len() {
+            if self.table.seats[index].folded {
+                continue;
+            }
+            self.table.seats[index].hand.add(self.shoe.draw().unwrap());
+            self.down[index].push(hidden);
+        }
+    }
+
+    fn shown(&self, index: usize) -> Hand {
+        let mut hand = Hand::new();
+        for (card, &hidden) in self.table.seats[index].hand.cards.iter().zip(&self.down[index]) {
+            if !hidden {
+                hand.add(*card);
+            }
+        }
+        hand
+    }
+
+    fn bringer(&self) -> usize {
+        let seats = 0..self.table.seats.len();
+        let upcard = |i: usize| self.shown(i).cards[0];
+
+        if self.variant == Variant::Razz {
+            seats.max_by_key(|&i| (low_score(&upcard(i)), order(upcard(i)))).unwrap()
+        } else {
+            seats.min_by_key(|&i| (upcard(i).value.score(), order(upcard(i)))).unwrap()
+        }
+    }
+
+    fn leader(&self) -> usize {
+        let live = self.table.players();
+        let mut best = live[0];
+        for &index in &live[1..] {
+            let ahead = if self.variant == Variant::Razz {
+                ace_five(&self.shown(index).cards) > ace_five(&self.shown(best).cards)
+            } else {
+                evaluate(&self.shown(index), &Hand::new()) > evaluate(&self.shown(best), &Hand::new())
+            };
+            if ahead {
+                best = index;
+            }
+        }
+        best
+    }
+
+    fn street(&mut self, phase: Phase) -> Result<(), Error> {
+        let bet = self.table.bet;
+        let size = if phase == Phase::Fourth { bet } else { bet.times(2)? };
+        self.give(phase == Phase::Seventh);
+        self.phase = phase;
+        self.table.open(self.leader(), size);
+        Ok(())
+    }
+
+    fn flow(&mut self) -> Result<(), Error> {
+        loop {
+            let variant = self.variant;
+            match self.table.walk(|hand| strength(hand, variant))? {
+                Round::Wait => return Ok(()),
+                Round::Over => return self.showdown(),
+                Round::Done => {}
+            }
+
+            match self.phase {
+                Phase::Third => self.street(Phase::Fourth)?,
+                Phase::Fourth => self.street(Phase::Fifth)?,
+                Phase::Fifth => self.street(Phase::Sixth)?,
+                Phase::Sixth => self.street(Phase::Seventh)?,
+                _ => return self.showdown(),
+            }
+        }
+    }
+
+    fn showdown(&mut self) -> Result<(), Error> {
+        self.phase = Phase::Result;
+        if let Some(text) = self.table.take()? {
+            self.message = text;
+            return Ok(());
+        }
+
+        let live = self.table.players();
+        let highs = if self.variant == Variant::Razz { Vec::new() } else { self.highs(&live) };
+        let lows = if self.variant == Variant::High { Vec::new() } else { self.lows(&live) };
+
+        let high = |winners: &[usize]| evaluate(&self.table.seats[winners[0]].hand, &Hand::new()).rank.to_string();
+        let low = |winners: &[usize]| ace_five(&self.table.seats[winners[0]].hand.cards).to_string();
+        let names = |winners: &[usize]| self.table.names(winners);
+
+        self.message = if lows.is_empty() {
+            format!("{} wins with {}", names(&highs), high(&highs))
+        } else if highs.is_empty() {
+            format!("{} wins with {}", names(&lows), low(&lows))
+        } else if highs == lows {
+            format!("{} scoops", names(&highs))
+        } else {
+            format!("High: {} {}, Low: {} {}", names(&highs), high(&highs), names(&lows), low(&lows))
+        };
+
+        if highs.is_empty() { self.table.award(&lows) } else { self.table.split(&highs, &lows) }
+    }
+
+    fn highs(&self, live: &[usize]) -> Vec<usize> {
+        self.table.best(live, |hand| evaluate(hand, &Hand::new()))
+    }
+
+    fn lows(&self, live: &[usize]) -> Vec<usize> {
+        let variant = self.variant;
+        let score = |hand: &Hand| if variant == Variant::Eight { eight(&hand.cards) } else { Some(ace_five(&hand.cards)) };
+        let best = self.table.best(live, score);
+        if best.first().is_some_and(|&i| score(&self.table.seats[i].hand).is_none()) { Vec::new() } else { best }
+    }
+
+    fn handle_ante(&mut self, key: KeyEvent) {
+        match self.table.wager(key) {
+            Step::Quit => self.phase = Phase::End,
+            Step::Deal => {
+                if let Err(error) = self.deal() {
+                    self.message = error.to_string();
+                }
+            }
+            Step::Short(text) => self.message = text,
+            _ => {}
+        }
+    }
+
+    fn handle_action(&mut self, key: KeyEvent) {
+        match self.table.respond(key) {
+            Step::Acted => {
+                if let Err(error) = self.flow() {
+                    self.message = error.to_string();
+                }
+            }
+            Step::Short(text) => self.message = text,
+            _ => {}
+        }
+    }
+
+    fn handle_result(&mut self, key: KeyEvent) {
+        match self.table.rebet(key) {
+            Step::Quit => self.phase = Phase::End,
+            Step::Next => {
+                self.message.clear();
+                self.phase = Phase::Ante;
+            }
+            _ => {}
+        }
+    }
+
+    fn status(&self, frame: &mut Frame, area: Rect) {
+        render_status(frame, &self.table.status(), area);
+    }
+
+    fn help(&self, frame: &mut Frame, area: Rect) {
+        let lines = match self.phase {
+            Phase::Ante => vec![
+                Line::raw("[↑↓] Bet"),
+                Line::raw("[Enter] Deal"),
+                Line::raw("[Q] Quit"),
+            ],
+            Phase::Result => vec![
+                Line::raw("[Enter] Next"),
+                Line::raw("[Q] Quit"),
+            ],
+            Phase::End => vec![Line::raw("")],
+            _ => self.table.prompt(),
+        };
+        render_help(frame, &lines, area);
+    }
+
+    fn scene(&mut self, frame: &mut Frame, area: Rect) {
+        let chunk = area.height / 2;
+        let bots = self.table.seats.len() as u16 - 1;
+        let width = area.width / bots;
+        let reveal = self.phase == Phase::Result && self.table.live() > 1;
+
+        for (i, seat) in self.table.seats.iter().enumerate().skip(1) {
+            let x = area.x + (i as u16 - 1) * width;
+            let label = Rect::new(x + 2, area.y, width.saturating_sub(2), 1);
+            let cards = Rect::new(x, area.y + 1, width, chunk.saturating_sub(1));
+
+            frame.render_widget(Line::raw(self.table.label(i)), label);
+
+            if seat.folded {
+                continue;
+            }
+            if reveal {
+                render_hand(frame, &seat.hand, cards, false);
+            } else {
+                render_mask(frame, &seat.hand, cards, &self.down[i]);
+            }
+        }
+
+        let player = &self.table.seats[0];
+        let label = Rect::new(area.x + 2, area.y + chunk, area.width.saturating_sub(2), 1);
+        let cards = Rect::new(area.x, area.y + chunk + 1, area.width, chunk.saturating_sub(1));
+
+        let rank = match self.variant {
+            Variant::Razz => ace_five(&player.hand.cards).to_string(),
+            _ => evaluate(&player.hand, &Hand::new()).rank.to_string(),
+        };
+        frame.render_widget(Line::raw(format!("{}: {} {}", player.name, rank, player.last)), label);
+        render_hand(frame, &player.hand, cards, false);
+    }
+}
+
+fn strength(hand: &Hand, variant: Variant) -> f32 {
+    let high = evaluate(hand, &Hand::new()).strength();
+    let low = ace_five(&hand.cards).strength();
+    let mut strength = match variant {
+        Variant::High => high,
+        Variant::Razz => low,
+        Variant::Eight => high.max(low),
+    };
+
+    if hand.cards.len() < 7 {
+        strength += (7 - hand.cards.len()) as f32 * 0.03;
+        let flushy = SUITS.iter().any(|&suit| hand.cards.iter().filter(|card| card.suit == suit).count() >= 3);
+        if flushy {
+            strength += 0.15;
+        }
+    }
+    strength
+}
+
+fn order(card: Card) -> u8 {
+    match card.suit {
+        Suit::Clubs => 0,
+        Suit::Diamonds => 1,
+        Suit::Hearts => 2,
+        Suit::Spades => 3,
+    }
+}
+
+impl Play for Stud {
+    fn handle(&mut self, key: KeyEvent) {
+        match self.phase {
+            Phase::Ante => self.handle_ante(key),
+            Phase::Result => self.handle_result(key),
+            Phase::End => {}
+            _ => self.handle_action(key),
+        }
+    }
+
+    fn render(&mut self, frame: &mut Frame, area: Rect) {
+        let inner = area.inner(Margin::new(1, 1));
+
+        let top = Rect::new(inner.x, inner.y, inner.width, 4);
+        let bottom = Rect::new(inner.x, inner.y + inner.height.saturating_sub(5), inner.width, 5);
+        let middle = Rect::new(inner.x, inner.y + 4, inner.width, inner.height.saturating_sub(9));
+
+        self.status(frame, top);
+        self.help(frame, bottom);
+
+        if self.phase != Phase::Ante {
+            self.scene(frame, middle);
+        }
+
+        render_pop(frame, &self.message, inner);
+    }
+
+    fn active(&self) -> bool {
+        self.phase != Phase::End
+    }
+
+    fn chips(&mut self) -> Option<&mut Wallet> {
+        Some(&mut self.table.seats[0].money)
+    }
+
+    fn prime(&mut self, cards: Vec<Card>) -> Result<(), String> {
+        self.shoe.prime(cards)
+    }
+}
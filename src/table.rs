@@ -0,0 +1,382 @@
+use crossterm::event::{KeyCode, KeyEvent};
+use rand::RngExt;
+use ratatui::text::Line;
+
+use crate::{
+    card::{roll, Hand},
+    chips::{Chips, Error, Wallet, BANKROLL, HOUSE, STEP},
+};
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum Act {
+    Fold,
+    Call,
+    Raise,
+}
+
+pub enum Step {
+    Quit,
+    Deal,
+    Acted,
+    Next,
+    Short(String),
+    Wait,
+}
+
+pub enum Round {
+    Wait,
+    Over,
+    Done,
+}
+
+pub struct Seat {
+    pub name: String,
+    pub hand: Hand,
+    pub money: Wallet,
+    pub stake: Chips,
+    pub folded: bool,
+    pub last: String,
+}
+
+impl Seat {
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.into(),
+            hand: Hand::new(),
+            money: Wallet::new(Chips::ZERO),
+            stake: Chips::ZERO,
+            folded: false,
+            last: String::new(),
+        }
+    }
+}
+
+pub struct Table {
+    pub seats: Vec<Seat>,
+    pub pot: Wallet,
+    pub bank: Wallet,
+    pub bet: Chips,
+    pub high: Chips,
+    pub size: Chips,
+    pub raises: u8,
+    pub turn: usize,
+    pub left: usize,
+}
+
+impl Table {
+    pub fn new(seats: Vec<Seat>) -> Self {
+        Self {
+            seats,
+            pot: Wallet::new(Chips::ZERO),
+            bank: Wallet::new(HOUSE),
+            bet: STEP,
+            high: Chips::ZERO,
+            size: Chips::ZERO,
+            raises: 0,
+            turn: 0,
+            left: 0,
+        }
+    }
+
+    pub fn reset(&mut self) {
+        for seat in &mut self.seats {
+            seat.hand.cards.clear();
+            seat.stake = Chips::ZERO;
+            seat.folded = false;
+            seat.last.clear();
+        }
+    }
+
+    pub fn live(&self) -> usize {
+        self.seats.iter().filter(|seat| !seat.folded).count()
+    }
+
+    pub fn players(&self) -> Vec<usize> {
+        (0..self.seats.len()).filter(|&i| !self.seats[i].folded).collect()
+    }
+
+    pub fn next(&self, from: usize) -> usize {
+        let count = self.seats.len();
+        let mut index = (from + 1) % count;
+        while self.seats[index].folded && index != from {
+            index = (index + 1) % count;
+        }
+        index
+    }
+
+    pub fn refill(&mut self, floor: Chips) -> Result<(), Error> {
+        for seat in self.seats.iter_mut().skip(1) {
+            if !seat.money.covers(floor) {
+                let top = BANKROLL.minus(seat.money.balance()).unwrap_or(Chips::ZERO);
+                self.bank.transfer(&mut seat.money, top)?;
+            }
+        }
+        Ok(())
+    }
+
+    pub fn owed(&self, index: usize) -> Chips {
+        self.high.minus(self.seats[index].stake).unwrap_or(Chips::ZERO)
+    }
+
+    pub fn pay(&mut self, index: usize, amount: Chips) -> Result<(), Error> {
+        let seat = &mut self.seats[index];
+        let stake = seat.stake.plus(amount)?;
+        seat.money.transfer(&mut self.pot, amount)?;
+        seat.stake = stake;
+        Ok(())
+    }
+
+    pub fn ante(&mut self, amount: Chips) -> Result<(), Error> {
+        for index in 0..self.seats.len() {
+            self.pay(index, amount)?;
+            self.seats[index].stake = Chips::ZERO;
+        }
+        Ok(())
+    }
+
+    pub fn open(&mut self, first: usize, size: Chips) {
+        for seat in &mut self.seats {
+            seat.stake = Chips::ZERO;
+        }
+        self.high = Chips::ZERO;
+        self.size = size;
+        self.raises = 0;
+        self.left = self.live();
+        self.turn = if self.seats[first].folded { self.next(first) } else { first };
+    }
+
+    pub fn post(&mut self, index: usize, amount: Chips) -> Result<(), Error> {
+        self.pay(index, amount)?;
+        self.high = self.high.max(self.seats[index].stake);
+        Ok(())
+    }
+
+    pub fn bring(&mut self, index: usize, amount: Chips) -> Result<(), Error> {
+        self.post(index, amount)?;
+        self.seats[index].last = format!("Bring-in {}", amount);
+        self.left = self.left.saturating_sub(1);
+        self.turn = self.next(index);
+        Ok(())
+    }
+
+    pub fn cost(&self, index: usize, act: Act) -> Result<Chips, Error> {
+        Ok(match act {
+            Act::Fold => Chips::ZERO,
+            Act::Call => self.owed(index),
+            Act::Raise => {
+                let steps = self.high.total() / self.size.total().max(1) + 1;
+                self.size.times(steps)?.minus(self.seats[index].stake).unwrap_or(Chips::ZERO)
+            }
+        })
+    }
+
+    pub fn afford(&self, index: usize, act: Act) -> Act {
+        let act = if act == Act::Raise && self.raises >= 4 { Act::Call } else { act };
+        let covers = |act| self.cost(index, act).is_ok_and(|cost| self.seats[index].money.covers(cost));
+        match act {
+            Act::Raise if !covers(Act::Raise) => self.afford(index, Act::Call),
+            Act::Call if !covers(Act::Call) => Act::Fold,
+            act => act,
+        }
+    }
+
+    pub fn act(&mut self, act: Act) -> Result<(), Error> {
+        let index = self.turn;
+        let owed = self.owed(index);
+
+        let act = if act == Act::Raise && self.raises >= 4 { Act::Call } else { act };
+        let cost = self.cost(index, act)?;
+
+        match act {
+            Act::Fold => {
+                self.seats[index].folded = true;
+                self.seats[index].last = "Fold".into();
+                self.left = self.left.saturating_sub(1);
+            }
+            Act::Call => {
+                self.pay(index, cost)?;
+                self.seats[index].last = if owed.is_zero() { "Check".into() } else { format!("Call {}", owed) };
+                self.left = self.left.saturating_sub(1);
+            }
+            Act::Raise => {
+                self.pay(index, cost)?;
+                self.high = self.high.max(self.seats[index].stake);
+                self.raises += 1;
+                self.seats[index].last = if self.raises == 1 { format!("Bet {}", self.high) } else { format!("Raise {}", self.high) };
+                self.left = self.live().saturating_sub(1);
+            }
+        }
+
+        self.turn = self.next(index);
+        Ok(())
+    }
+
+    pub fn done(&self) -> bool {
+        self.left == 0 || self.live() <= 1
+    }
+
+    pub fn walk(&mut self, strength: impl Fn(&Hand) -> f32) -> Result<Round, Error> {
+        while !self.done() && self.turn != 0 {
+            let index = self.turn;
+            let act = think(strength(&self.seats[index].hand), self.owed(index));
+            self.act(self.afford(index, act))?;
+        }
+
+        Ok(if !self.done() {
+            Round::Wait
+        } else if self.live() <= 1 {
+            Round::Over
+        } else {
+            Round::Done
+        })
+    }
+
+    pub fn take(&mut self) -> Result<Option<String>, Error> {
+        let live = self.players();
+        let [winner] = live[..] else {
+            return Ok(None);
+        };
+        let text = format!("{} takes {}", self.seats[winner].name, self.pot.balance());
+        self.award(&live)?;
+        Ok(Some(text))
+    }
+
+    pub fn best<T: Ord>(&self, live: &[usize], score: impl Fn(&Hand) -> T) -> Vec<usize> {
+        let scores: Vec<T> = live.iter().map(|&i| score(&self.seats[i].hand)).collect();
+        let Some(best) = scores.iter().max() else {
+            return Vec::new();
+        };
+        live.iter().zip(&scores).filter(|(_, score)| *score == best).map(|(&i, _)| i).collect()
+    }
+
+    pub fn names(&self, winners: &[usize]) -> String {
+        match winners {
+            [one] => self.seats[*one].name.clone(),
+            _ => "Split".into(),
+        }
+    }
+
+    pub fn wager(&mut self, key: KeyEvent) -> Step {
+        let money = self.seats[0].money.balance();
+        match key.code {
+            KeyCode::Char('q') => Step::Quit,
+            KeyCode::Up | KeyCode::Char('w') => {
+                self.bet = self.bet.raise(STEP, money);
+                Step::Wait
+            }
+            KeyCode::Down | KeyCode::Char('s') => {
+                self.bet = self.bet.lower(STEP, STEP);
+                Step::Wait
+            }
+            KeyCode::Enter if self.bet > money => Step::Short(format!("Need {}, have {}", self.bet, money)),
+            KeyCode::Enter => Step::Deal,
+            _ => Step::Wait,
+        }
+    }
+
+    pub fn respond(&mut self, key: KeyEvent) -> Step {
+        let act = match key.code {
+            KeyCode::Char('c') => Act::Call,
+            KeyCode::Char('r') => Act::Raise,
+            KeyCode::Char('f') => Act::Fold,
+            _ => return Step::Wait,
+        };
+        if self.turn != 0 {
+            return Step::Wait;
+        }
+        match self.act(act) {
+            Ok(()) => Step::Acted,
+            Err(error) => Step::Short(error.to_string()),
+        }
+    }
+
+    pub fn rebet(&mut self, key: KeyEvent) -> Step {
+        let money = self.seats[0].money.balance();
+        match key.code {
+            KeyCode::Char('q') => Step::Quit,
+            KeyCode::Enter if money.is_zero() => Step::Quit,
+            KeyCode::Enter => {
+                self.bet = STEP.min(money);
+                Step::Next
+            }
+            _ => Step::Wait,
+        }
+    }
+
+    pub fn status(&self) -> [Line<'static>; 3] {
+        [
+            Line::raw(format!("Money: {}", self.seats[0].money.balance())),
+            Line::raw(format!("Bet: {}", self.bet)),
+            Line::raw(format!("Pot: {}", self.pot.balance())),
+        ]
+    }
+
+    pub fn prompt(&self) -> Vec<Line<'static>> {
+        vec![
+            Line::raw(if self.owed(0).is_zero() { "[C] Check" } else { "[C] Call" }),
+            Line::raw(if self.high.is_zero() { "[R] Bet" } else { "[R] Raise" }),
+            Line::raw("[F] Fold"),
+        ]
+    }
+
+    pub fn label(&self, index: usize) -> String {
+        let seat = &self.seats[index];
+        format!("{} {} {}", seat.name, seat.money.balance(), seat.last)
+    }
+
+    pub fn award(&mut self, winners: &[usize]) -> Result<(), Error> {
+        if winners.is_empty() {
+            return Ok(());
+        }
+        let (share, _) = self.pot.balance().split(winners.len() as u64);
+        for &index in winners {
+            self.pot.transfer(&mut self.seats[index].money, share)?;
+        }
+        let extra = self.pot.balance();
+        self.pot.transfer(&mut self.seats[winners[0]].money, extra)
+    }
+
+    pub fn split(&mut self, high: &[usize], low: &[usize]) -> Result<(), Error> {
+        if low.is_empty() {
+            return self.award(high);
+        }
+        let (half, _) = self.pot.balance().split(2);
+        let mut lows = Wallet::new(Chips::ZERO);
+        self.pot.transfer(&mut lows, half)?;
+        self.award(high)?;
+        self.pot = lows;
+        self.award(low)
+    }
+}
+
+pub fn think(strength: f32, owed: Chips) -> Act {
+    let roll: f32 = roll(|rng| rng.random());
+    if strength > 0.7 && roll < strength {
+        Act::Raise
+    } else if owed.is_zero() {
+        if roll < 0.08 { Act::Raise } else { Act::Call }
+    } else if strength > 0.3 || roll < 0.1 {
+        Act::Call
+    } else {
+        Act::Fold
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn short_calls_are_rejected() {
+        let mut table = Table::new(vec![Seat::new("You"), Seat::new("Bot")]);
+        table.bank.transfer(&mut table.seats[0].money, Chips::dollars(15)).unwrap();
+        table.bank.transfer(&mut table.seats[1].money, Chips::dollars(100)).unwrap();
+        table.open(1, Chips::dollars(20));
+        table.act(Act::Raise).unwrap();
+
+        assert!(table.act(Act::Call).is_err());
+        assert_eq!(table.seats[0].money.balance(), Chips::dollars(15));
+        assert_eq!(table.pot.balance(), Chips::dollars(20));
+        assert!(table.afford(0, Act::Raise) == Act::Fold);
+    }
+}
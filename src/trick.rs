@@ -0,0 +1,62 @@
+use crate::card::{Card, Hand, Suit};
+
+pub struct Trick {
+    pub leader: usize,
+    pub seats: usize,
+    pub cards: Vec<(usize, Card)>,
+}
+
+impl Trick {
+    pub fn new(leader: usize, seats: usize) -> Self {
+        Self {
+            leader,
+            seats,
+            cards: Vec::new(),
+        }
+    }
+
+    pub fn led(&self) -> Option<Suit> {
+        self.cards.first().map(|(_, card)| card.suit)
+    }
+
+    pub fn turn(&self) -> usize {
+        (self.leader + self.cards.len()) % self.seats
+    }
+
+    pub fn done(&self) -> bool {
+        self.cards.len() == self.seats
+    }
+
+    pub fn play(&mut self, card: Card) {
+        let seat = self.turn();
+        self.cards.push((seat, card));
+    }
+
+    pub fn legal(&self, hand: &Hand) -> Vec<usize> {
+        let all: Vec<usize> = (0..hand.cards.len()).collect();
+        let Some(led) = self.led() else {
+            return all;
+        };
+        let follow: Vec<usize> = all.iter().copied().filter(|&i| hand.cards[i].suit == led).collect();
+        if follow.is_empty() { all } else { follow }
+    }
+
+    pub fn best(&self, trump: Option<Suit>) -> Option<(usize, Card)> {
+        let led = self.led()?;
+        let power = |card: &Card| {
+            let tier = if Some(card.suit) == trump {
+                2
+            } else if card.suit == led {
+                1
+            } else {
+                0
+            };
+            (tier, card.value.score())
+        };
+        self.cards.iter().copied().max_by_key(|(_, card)| power(card))
+    }
+
+    pub fn winner(&self, trump: Option<Suit>) -> usize {
+        self.best(trump).map(|(seat, _)| seat).unwrap_or(self.leader)
+    }
+}
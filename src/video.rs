@@ -0,0 +1,305 @@
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::{
+    layout::{Margin, Rect},
+    style::{Color, Style},
+    text::Line,
+    widgets::Paragraph,
+    Frame,
+};
+
+use crate::{
+    card::{Card, Hand, Shoe, Value},
+    chips::{net, Chips, Error, Wallet, HOUSE, STEP},
+    play::Play,
+    poker::{wild, Rank},
+    show::{render_hand, render_help, render_pick, render_pop, render_status},
+};
+
+#[derive(Clone, Copy)]
+pub struct Rules {
+    pub name: &'static str,
+    pub jokers: u8,
+    pub wild: &'static [Value],
+    pub table: &'static [(&'static str, u64)],
+}
+
+pub const DEUCES: Rules = Rules {
+    name: "Deuces Wild",
+    jokers: 0,
+    wild: &[Value::Two],
+    table: &[
+        ("Natural Royal", 800),
+        ("Four Deuces", 200),
+        ("Wild Royal", 25),
+        ("Five of a Kind", 15),
+        ("Straight Flush", 9),
+        ("Four of a Kind", 5),
+        ("Full House", 3),
+        ("Flush", 2),
+        ("Straight", 2),
+        ("Three of a Kind", 1),
+    ],
+};
+
+pub const JOKER: Rules = Rules {
+    name: "Joker Poker",
+    jokers: 1,
+    wild: &[],
+    table: &[
+        ("Natural Royal", 800),
+        ("Five of a Kind", 200),
+        ("Wild Royal", 100),
+        ("Straight Flush", 50),
+        ("Four of a Kind", 20),
+        ("Full House", 7),
+        ("Flush", 5),
+        ("Straight", 3),
+        ("Three of a Kind", 2),
+        ("Two Pair", 1),
+        ("Kings or Better", 1),
+    ],
+};
+
+impl Rules {
+    pub fn hand(&self, cards: &[Card]) -> String {
+        let score = wild(cards, self.wild);
+        let wilds = cards.iter().filter(|card| card.is_wild(self.wild)).count();
+        let deuces = cards.iter().filter(|card| card.value == Value::Two).count();
+        let royal = score.rank == Rank::StraightFlush && score.power == 14;
+
+        match score.rank {
+            _ if royal && wilds == 0 => "Natural Royal".into(),
+            _ if self.wild.contains(&Value::Two) && deuces == 4 => "Four Deuces".into(),
+            _ if royal => "Wild Royal".into(),
+            Rank::Pair if score.top() >= 13 => "Kings or Better".into(),
+            Rank::High | Rank::Pair => String::new(),
+            rank => rank.to_string(),
+        }
+    }
+
+    pub fn pays(&self, name: &str) -> u64 {
+        self.table.iter().find(|(line, _)| *line == name).map_or(0, |&(_, pays)| pays)
+    }
+}
+
+#[derive(PartialEq)]
+pub enum Phase {
+    Bet,
+    Hold,
+    Result,
+    End,
+}
+
+pub struct Video {
+    pub phase: Phase,
+    pub rules: Rules,
+    pub shoe: Shoe,
+    pub hand: Hand,
+    pub name: String,
+    pub cursor: usize,
+    pub holds: Vec<bool>,
+    pub money: Wallet,
+    pub bank: Wallet,
+    pub bet: Chips,
+    pub stake: Chips,
+    pub message: String,
+}
+
+impl Video {
+    pub fn new(rules: Rules) -> Self {
+        Self {
+            phase: Phase::Bet,
+            rules,
+            shoe: Shoe::jokers(1, rules.jokers),
+            hand: Hand::new(),
+            name: String::new(),
+            cursor: 0,
+            holds: vec![false; 5],
+            money: Wallet::new(Chips::ZERO),
+            bank: Wallet::new(HOUSE),
+            bet: STEP,
+            stake: Chips::ZERO,
+            message: String::new(),
+        }
+    }
+
+    fn deal(&mut self) -> Result<(), Error> {
+        self.money.transfer(&mut self.bank, self.bet)?;
+        self.stake = self.bet;
+        self.shoe.restock(Shoe::jokers(1, self.rules.jokers));
+        self.shoe.shuffle();
+        self.hand = Hand::new();
+        for _ in 0..5 {
+            self.hand.add(self.shoe.draw().unwrap());
+        }
+        self.name = self.rules.hand(&self.hand.cards);
+        self.cursor = 0;
+        self.holds = vec![false; 5];
+        self.message.clear();
+        self.phase = Phase::Hold;
+        Ok(())
+    }
+
+    fn redraw(&mut self) -> Result<(), Error> {
+        for (card, &hold) in self.hand.cards.iter_mut().zip(&self.holds) {
+            if !hold {
+                *card = self.shoe.draw().unwrap();
+            }
+        }
+        self.name = self.rules.hand(&self.hand.cards);
+
+        let pays = self.rules.pays(&self.name);
+        let paid = self.stake.times(pays)?;
+        self.bank.transfer(&mut self.money, paid)?;
+        self.message = match pays {
+            0 => format!("No win ({})", net(paid, self.stake)),
+            _ => format!("{} pays {} for 1 ({})", self.name, pays, net(paid, self.stake)),
+        };
+        if self.money.broke() {
+            self.message.push_str(" Bankrupt");
+        }
+        self.phase = Phase::Result;
+        Ok(())
+    }
+
+    fn handle_bet(&mut self, key: KeyEvent) {
+        match key.code {
+            KeyCode::Char('q') => self.phase = Phase::End,
+            KeyCode::Up | KeyCode::Char('w') => {
+                self.bet = self.bet.raise(STEP, self.money.balance());
+            }
+            KeyCode::Down | KeyCode::Char('s') => {
+                self.bet = self.bet.lower(STEP, STEP);
+            }
+            KeyCode::Enter => {
+                if let Err(error) = self.deal() {
+                    self.message = error.to_string();
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn handle_hold(&mut self, key: KeyEvent) {
+        match key.code {
+            KeyCode::Left | KeyCode::Char('a') => self.cursor = self.cursor.saturating_sub(1),
+            KeyCode::Right | KeyCode::Char('d') => self.cursor = (self.cursor + 1).min(4),
+            KeyCode::Char(' ') => self.holds[self.cursor] = !self.holds[self.cursor],
+            KeyCode::Enter => {
+                if let Err(error) = self.redraw() {
+                    self.message = error.to_string();
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn handle_result(&mut self, key: KeyEvent) {
+        match key.code {
+            KeyCode::Char('q') => self.phase = Phase::End,
+            KeyCode::Enter => {
+                if self.money.broke() {
+                    self.phase = Phase::End;
+                } else {
+                    self.bet = self.bet.min(self.money.balance());
+                    self.name.clear();
+                    self.message.clear();
+                    self.phase = Phase::Bet;
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn status(&self, frame: &mut Frame, area: Rect) {
+        let lines = [
+            Line::raw(format!("{}  Money: {}", self.rules.name, self.money.balance())),
+            Line::raw(format!("Bet: {}", self.bet)),
+        ];
+        render_status(frame, &lines, area);
+    }
+
+    fn help(&self, frame: &mut Frame, area: Rect) {
+        let lines = match self.phase {
+            Phase::Bet => vec![
+                Line::raw("[↑↓] Bet"),
+                Line::raw("[Enter] Deal"),
+                Line::raw("[Q] Quit"),
+            ],
+            Phase::Hold => vec![
+                Line::raw("[←→] Move  [Space] Hold"),
+                Line::raw("[Enter] Draw"),
+            ],
+            Phase::Result => vec![
+                Line::raw("[Enter] Next"),
+                Line::raw("[Q] Quit"),
+            ],
+            _ => vec![Line::raw("")],
+        };
+        render_help(frame, &lines, area);
+    }
+
+    fn scene(&mut self, frame: &mut Frame, area: Rect) {
+        let rows = self.rules.table.len() as u16;
+        let table = Rect::new(area.x + 2, area.y, area.width.saturating_sub(2), rows.min(area.height));
+        let lines: Vec<Line> = self
+            .rules
+            .table
+            .iter()
+            .map(|&(line, pays)| {
+                let text = Line::raw(format!("{:<18}{:>8}", line, self.bet.times(pays).unwrap_or(self.bet)));
+                if line == self.name { text.style(Style::default().fg(Color::Yellow)) } else { text }
+            })
+            .collect();
+        frame.render_widget(Paragraph::new(lines), table);
+
+        if self.phase == Phase::Bet {
+            return;
+        }
+
+        let hand = Rect::new(area.x, area.y + area.height.saturating_sub(8), area.width, 8.min(area.height));
+        frame.render_widget(Line::raw(self.name.as_str()), Rect::new(hand.x + 2, hand.y, hand.width.saturating_sub(2), 1));
+        let cards = Rect::new(hand.x, hand.y + 1, hand.width, hand.height.saturating_sub(1));
+        match self.phase {
+            Phase::Hold => render_pick(frame, &self.hand, cards, self.cursor, &self.holds),
+            _ => render_hand(frame, &self.hand, cards, false),
+        }
+    }
+}
+
+impl Play for Video {
+    fn handle(&mut self, key: KeyEvent) {
+        match self.phase {
+            Phase::Bet => self.handle_bet(key),
+            Phase::Hold => self.handle_hold(key),
+            Phase::Result => self.handle_result(key),
+            _ => {}
+        }
+    }
+
+    fn render(&mut self, frame: &mut Frame, area: Rect) {
+        let inner = area.inner(Margin::new(1, 1));
+
+        let top = Rect::new(inner.x, inner.y, inner.width, 3);
+        let bottom = Rect::new(inner.x, inner.y + inner.height.saturating_sub(4), inner.width, 4);
+        let middle = Rect::new(inner.x, inner.y + 3, inner.width, inner.height.saturating_sub(7));
+
+        self.status(frame, top);
+        self.help(frame, bottom);
+        self.scene(frame, middle);
+
+        render_pop(frame, &self.message, inner);
+    }
+
+    fn active(&self) -> bool {
+        self.phase != Phase::End
+    }
+
+    fn chips(&mut self) -> Option<&mut Wallet> {
+        Some(&mut self.money)
+    }
+
+    fn prime(&mut self, cards: Vec<Card>) -> Result<(), String> {
+        self.shoe.prime(cards)
+    }
+}